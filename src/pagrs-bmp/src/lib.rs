@@ -9,23 +9,10 @@ use embedded_graphics_core::geometry::Point;
 use embedded_graphics_core::pixelcolor::{BinaryColor, PixelColor, Rgb555, Rgb565, Rgb888};
 use embedded_layout::align::{horizontal, vertical, Align};
 use tinybmp::Bmp;
-use pagrs_core::Page;
+use pagrs_core::{Page, Refresh};
 
-#[derive(Debug, PartialEq)]
-/// define the horizontal alignment of the image: centered, on the left or on the right?
-pub enum HorizontalAlignment {
-    Left,
-    Center,
-    Right
-}
-
-#[derive(Debug, PartialEq)]
-/// define the vertical alignment of the image: centered, at the top or at the bottom?
-pub enum VerticalAlignment {
-    Top,
-    Center,
-    Bottom
-}
+// the alignments are shared with other pages, e.g. the text box of `pagrs-text`
+pub use pagrs_core::{HorizontalAlignment, VerticalAlignment};
 
 #[derive(Debug)]
 /// show a fixed, static image.
@@ -131,7 +118,7 @@ where
         Ok(())
     }
 
-    fn frames_per_second(&self) -> u8 {
-        1
+    fn refresh(&self) -> Refresh {
+        Refresh::OnDemand
     }
 }
@@ -0,0 +1,17 @@
+/// define the horizontal alignment of a content, e.g. an image or a text: centered, on the left or on the right?
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right
+}
+
+/// define the vertical alignment of a content, e.g. an image or a text: centered, at the top or at the bottom?
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VerticalAlignment {
+    Top,
+    Center,
+    Bottom
+}
@@ -0,0 +1,117 @@
+use display_interface::{AsyncWriteOnlyDataCommand, DisplayError};
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::Point;
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use ssd1306::mode::{BufferedGraphicsModeAsync, DisplayConfigAsync};
+use ssd1306::prelude::Brightness;
+use ssd1306::size::DisplaySizeAsync;
+use ssd1306::Ssd1306Async;
+use crate::orientation::Orientation;
+use crate::scroll::Scroll;
+
+/// the output the [PageRotator](crate::PageRotator) renders the [pages](crate::Page) onto.
+///
+/// A backend is a buffered [DrawTarget]: the pages draw into the buffer and the rotator
+/// [`flush`](Backend::flush)-es it to the physical display(s) once per frame.
+///
+/// implementations exist for:
+/// - a single [Ssd1306Async] in buffered graphics mode
+/// - the [MirrorBackend](crate::MirrorBackend), showing the same content on several displays
+/// - the [SpanBackend](crate::SpanBackend), spanning one large canvas across several displays
+/// - the [ScrollingSsd1306](crate::ScrollingSsd1306), a single SSD1306 driven via its interface, scrolling in hardware
+#[allow(async_fn_in_trait)]
+pub trait Backend: DrawTarget<Color = BinaryColor, Error = DisplayError> {
+    /// initialize the physical display(s)
+    async fn init(&mut self) -> Result<(), DisplayError>;
+
+    /// reset the buffer, so the next frame starts on a blank display
+    fn clear_buffer(&mut self);
+
+    /// send the content of the buffer to the physical display(s)
+    async fn flush(&mut self) -> Result<(), DisplayError>;
+
+    /// change the brightness (contrast) of the physical display(s). Ignored by default.
+    async fn set_brightness(&mut self, brightness: u8) -> Result<(), DisplayError> {
+        let _ = brightness;
+        Ok(())
+    }
+
+    /// rotate the content of the physical display(s), swapping width and height of the [DrawTarget] for a portrait
+    /// [Orientation]. Ignored by default.
+    async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), DisplayError> {
+        let _ = orientation;
+        Ok(())
+    }
+
+    /// invert the colors of the physical display(s), showing lit pixels dark and vice versa. Ignored by default.
+    async fn set_inverted(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        let _ = inverted;
+        Ok(())
+    }
+
+    /// start scrolling a region of the physical display(s) in hardware, until [`stop_scroll`](Backend::stop_scroll)
+    /// is called. Returns `false` if the backend can't scroll in hardware, which is the default, so the
+    /// [PageRotator](crate::PageRotator) scrolls in software instead, as long as the backend can read back its frame
+    /// via [`read_pixel`](Backend::read_pixel).
+    async fn start_scroll(&mut self, scroll: &Scroll) -> Result<bool, DisplayError> {
+        let _ = scroll;
+        Ok(false)
+    }
+
+    /// stop the hardware scrolling started via [`start_scroll`](Backend::start_scroll). Ignored by default.
+    async fn stop_scroll(&mut self) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
+    /// read back the pixel at `point` of the last frame, used to [capture](crate::PageController::capture) it and to
+    /// scroll in software.
+    /// `None` if outside the frame or if the backend can't read back its buffer, which is the default.
+    fn read_pixel(&self, point: Point) -> Option<BinaryColor> {
+        let _ = point;
+        None
+    }
+}
+
+impl<DI, SIZE> Backend for Ssd1306Async<DI, SIZE, BufferedGraphicsModeAsync<SIZE>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        DisplayConfigAsync::init(self).await
+    }
+
+    fn clear_buffer(&mut self) {
+        Ssd1306Async::clear_buffer(self)
+    }
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        Ssd1306Async::flush(self).await
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> Result<(), DisplayError> {
+        // the lowest precharge period is used by the SSD1306 for its dimmest preset only
+        let precharge = if brightness == 0 { 0x1 } else { 0x2 };
+        Ssd1306Async::set_brightness(self, Brightness::custom(precharge, brightness)).await
+    }
+
+    async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), DisplayError> {
+        Ssd1306Async::set_rotation(self, orientation.into()).await
+    }
+
+    async fn set_inverted(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        Ssd1306Async::set_invert(self, inverted).await
+    }
+}
+
+/// combine the results of several displays: succeeds as long as at least one display is working
+pub(crate) fn combined_result(errors: &[Option<DisplayError>]) -> Result<(), DisplayError> {
+    if errors.iter().any(Option::is_none) {
+        return Ok(());
+    }
+
+    match errors.iter().flatten().next() {
+        Some(error) => Err(error.clone()),
+        None => Ok(()),
+    }
+}
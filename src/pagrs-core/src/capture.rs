@@ -0,0 +1,184 @@
+use core::fmt::Write as _;
+use embedded_graphics_core::geometry::{Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use heapless::String;
+
+/// size of the BMP file header plus the info header
+const BMP_HEADER_SIZE: usize = 14 + 40;
+/// size of the BMP color table: black and white, 4 bytes each
+const BMP_PALETTE_SIZE: usize = 2 * 4;
+
+/// errors while capturing or encoding a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CaptureError {
+    /// the [Backend](crate::Backend) can't read back its frame, e.g. a plain `Ssd1306Async` instead of a
+    /// [ScrollingSsd1306](crate::ScrollingSsd1306)
+    Unsupported,
+    /// the provided buffer can't hold the result
+    BufferTooSmall,
+}
+
+/// the amount of bytes needed to capture a frame of the given size, see [CapturedFrame]
+pub const fn capture_size(size: Size) -> usize {
+    size.width.div_ceil(8) as usize * size.height as usize
+}
+
+/// a copy of a frame, as shown on the display. Created by [`PageController::capture`](crate::PageController::capture).
+///
+/// the pixels are stored with 1 bit per pixel, row by row from top to bottom. Within a row the most significant bit
+/// is the leftmost pixel and each row is padded to full bytes. A set bit is a lit pixel.
+///
+/// ## example
+/// ```rust
+/// use embedded_graphics_core::geometry::{Point, Size};
+/// use embedded_graphics_core::pixelcolor::BinaryColor;
+/// use pagrs_core::CapturedFrame;
+///
+/// // a 10x2 frame with the top left and the bottom right pixel lit
+/// let data = [0b1000_0000, 0b0000_0000, 0b0000_0000, 0b0100_0000];
+/// let frame = CapturedFrame::new(Size::new(10, 2), &data).unwrap();
+/// assert_eq!(frame.pixel(Point::new(9, 1)), Some(BinaryColor::On));
+///
+/// // PBM: lit pixels are white, so the bits are inverted
+/// let mut pbm = [0; 32];
+/// let length = frame.encode_pbm(&mut pbm).unwrap();
+/// assert_eq!(&pbm[..length], b"P4\n10 2\n\x7f\xff\xff\xbf");
+///
+/// // BMP: rows from bottom to top, each padded to 4 bytes
+/// let mut bmp = [0; 128];
+/// let length = frame.encode_bmp(&mut bmp).unwrap();
+/// assert_eq!(length, 62 + 2 * 4);
+/// assert_eq!(&bmp[..2], b"BM");
+/// assert_eq!(&bmp[62..length], &[0, 0x40, 0, 0, 0x80, 0, 0, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapturedFrame<'b> {
+    size: Size,
+    data: &'b [u8],
+}
+
+impl<'b> CapturedFrame<'b> {
+    /// wrap the `data` of a frame with the given size, `None` if the data is too short.
+    pub fn new(size: Size, data: &'b [u8]) -> Option<Self> {
+        let length = capture_size(size);
+        (data.len() >= length).then(|| Self { size, data: &data[..length] })
+    }
+
+    /// the size of the frame in pixels
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// the pixels of the frame, see [CapturedFrame] for the layout
+    pub fn data(&self) -> &'b [u8] {
+        self.data
+    }
+
+    /// the color of the pixel at `point`, `None` if outside the frame
+    pub fn pixel(&self, point: Point) -> Option<BinaryColor> {
+        if point.x < 0 || point.y < 0 || point.x as u32 >= self.size.width || point.y as u32 >= self.size.height {
+            return None;
+        }
+
+        let byte = self.data[point.y as usize * self.row_size() + point.x as usize / 8];
+        Some((byte & (0x80 >> (point.x % 8)) != 0).into())
+    }
+
+    /// the amount of bytes of the frame encoded as binary PBM
+    pub fn pbm_size(&self) -> usize {
+        self.pbm_header().len() + self.data.len()
+    }
+
+    /// encode the frame as binary PBM (`P4`) into `buffer`, returning the amount of bytes written.
+    /// Lit pixels are white, as on the display.
+    pub fn encode_pbm(&self, buffer: &mut [u8]) -> Result<usize, CaptureError> {
+        let header = self.pbm_header();
+        let length = self.pbm_size();
+        if buffer.len() < length {
+            return Err(CaptureError::BufferTooSmall);
+        }
+
+        buffer[..header.len()].copy_from_slice(header.as_bytes());
+        // in PBM a set bit is black
+        for (target, byte) in buffer[header.len()..length].iter_mut().zip(self.data) {
+            *target = !byte;
+        }
+
+        Ok(length)
+    }
+
+    /// the amount of bytes of the frame encoded as BMP
+    pub fn bmp_size(&self) -> usize {
+        BMP_HEADER_SIZE + BMP_PALETTE_SIZE + self.bmp_row_size() * self.size.height as usize
+    }
+
+    /// encode the frame as monochrome BMP into `buffer`, returning the amount of bytes written.
+    /// Lit pixels are white, as on the display.
+    pub fn encode_bmp(&self, buffer: &mut [u8]) -> Result<usize, CaptureError> {
+        let length = self.bmp_size();
+        if buffer.len() < length {
+            return Err(CaptureError::BufferTooSmall);
+        }
+
+        let data_offset = (BMP_HEADER_SIZE + BMP_PALETTE_SIZE) as u32;
+        let image_size = length as u32 - data_offset;
+
+        // file header
+        buffer[0..2].copy_from_slice(b"BM");
+        buffer[2..6].copy_from_slice(&(length as u32).to_le_bytes());
+        buffer[6..10].fill(0);
+        buffer[10..14].copy_from_slice(&data_offset.to_le_bytes());
+        // info header
+        buffer[14..18].copy_from_slice(&40u32.to_le_bytes());
+        buffer[18..22].copy_from_slice(&self.size.width.to_le_bytes());
+        buffer[22..26].copy_from_slice(&self.size.height.to_le_bytes());
+        buffer[26..28].copy_from_slice(&1u16.to_le_bytes());
+        buffer[28..30].copy_from_slice(&1u16.to_le_bytes());
+        buffer[30..34].fill(0);
+        buffer[34..38].copy_from_slice(&image_size.to_le_bytes());
+        buffer[38..46].fill(0);
+        buffer[46..50].copy_from_slice(&2u32.to_le_bytes());
+        buffer[50..54].fill(0);
+        // color table: index 0 black, index 1 white
+        buffer[54..58].copy_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        buffer[58..62].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0x00]);
+
+        // the rows are stored from bottom to top
+        let row_size = self.row_size();
+        let bmp_row_size = self.bmp_row_size();
+        for (index, row) in self.data.chunks(row_size).rev().enumerate() {
+            let start = data_offset as usize + index * bmp_row_size;
+            buffer[start..start + row_size].copy_from_slice(row);
+            buffer[start + row_size..start + bmp_row_size].fill(0);
+        }
+
+        Ok(length)
+    }
+
+    fn row_size(&self) -> usize {
+        self.size.width.div_ceil(8) as usize
+    }
+
+    fn bmp_row_size(&self) -> usize {
+        self.row_size().div_ceil(4) * 4
+    }
+
+    fn pbm_header(&self) -> String<24> {
+        let mut header = String::new();
+        let _ = write!(header, "P4\n{} {}\n", self.size.width, self.size.height);
+        header
+    }
+}
+
+/// pack the 8 pixels of the byte with the given `index` of a captured frame, see [CapturedFrame] for the layout
+pub(crate) fn pack_byte(index: usize, size: Size, pixel: impl Fn(Point) -> Option<BinaryColor>) -> u8 {
+    let row_size = size.width.div_ceil(8) as usize;
+    let y = (index / row_size) as i32;
+    let x = (index % row_size * 8) as i32;
+
+    (0..8).fold(0, |byte, bit| match pixel(Point::new(x + bit, y)) {
+        Some(BinaryColor::On) => byte | (0x80 >> bit),
+        _ => byte,
+    })
+}
@@ -0,0 +1,183 @@
+use display_interface::DisplayError;
+use embassy_time::{Duration, Instant};
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::Dimensions;
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use heapless::Vec;
+use crate::viewport::Viewport;
+use crate::{InputEvent, Page, Refresh, DEFAULT_PAGE_DURATION};
+
+/// how often the carousel renders at least, to switch to the next child page in time
+const SLIDE_CHECK_REFRESH: Refresh = Refresh::FramesPerSecond(1);
+
+struct Slide<'a, D> {
+    page: &'a mut dyn for<'c> Page<Viewport<'c, D>>,
+    duration: Duration,
+}
+
+/// a page rotating its own set of child pages, independently of the [PageRotator](crate::PageRotator).
+///
+/// The carousel shows one child page at a time in its whole area and switches to the next one
+/// once the duration of the current child is over, calling the lifecycle methods of the children
+/// accordingly. Combined with a [SplitScreen](crate::SplitScreen) e.g. the lower half of the display can
+/// rotate while the upper half stays the same. It can also be registered to the [PageRotator](crate::PageRotator) directly.
+///
+/// When the carousel gets activated again, it continues with the child page it showed last.
+/// [Input events](crate::InputEvent) are forwarded to the child page currently shown.
+///
+/// ## type parameters
+/// - `D`: the display type, e.g. the [Backend](crate::Backend) of the [PageRotator](crate::PageRotator)
+/// - `PAGE_COUNT`: the maximum amount of child pages
+///
+/// ## example
+/// ```rust,ignore
+/// let mut carousel = Carousel::<_, 3>::new();
+/// let _ = carousel.add_page(&mut temperature);
+/// let _ = carousel.add_page_with_duration(&mut humidity, Duration::from_secs(2));
+///
+/// let mut split = SplitScreen::<_, 2>::new(SplitLayout::Rows(&[1, 1]));
+/// let _ = split.add_page(&mut clock);
+/// let _ = split.add_page(&mut carousel);
+/// ```
+pub struct Carousel<'a, D, const PAGE_COUNT: usize> {
+    slides: Vec<Slide<'a, D>, PAGE_COUNT>,
+    index: usize,
+    shown_since: Instant,
+}
+
+impl<'a, D, const PAGE_COUNT: usize> Carousel<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    /// create a new, empty [Carousel]
+    pub fn new() -> Self {
+        Self {
+            slides: Vec::new(),
+            index: 0,
+            shown_since: Instant::MIN,
+        }
+    }
+
+    /// register a new [Page] with the default duration for the rotation inside the carousel.
+    /// Returns the page back if already `PAGE_COUNT` pages are registered.
+    pub fn add_page<P: for<'c> Page<Viewport<'c, D>>>(&mut self, page: &'a mut P) -> Result<(), &'a mut P> {
+        self.add_page_with_duration(page, DEFAULT_PAGE_DURATION)
+    }
+
+    /// register a new [Page] with a custom duration for the rotation inside the carousel.
+    /// Returns the page back if already `PAGE_COUNT` pages are registered.
+    pub fn add_page_with_duration<P: for<'c> Page<Viewport<'c, D>>>(&mut self, page: &'a mut P, duration: Duration) -> Result<(), &'a mut P> {
+        if self.slides.is_full() {
+            return Err(page);
+        }
+
+        // can't fail, there is room left
+        let _ = self.slides.push(Slide { page, duration });
+
+        Ok(())
+    }
+
+    fn activate_slide(&mut self) -> Result<(), DisplayError> {
+        self.shown_since = Instant::now();
+        match self.slides.get_mut(self.index) {
+            Some(slide) => slide.page.activated(),
+            None => Ok(()),
+        }
+    }
+
+    fn render_slide(&mut self, viewport: &mut Viewport<'_, D>) -> Result<(), DisplayError> {
+        if self.slides.is_empty() {
+            return Ok(());
+        }
+
+        if self.shown_since.elapsed() >= self.slides[self.index].duration {
+            self.deactivate_slide()?;
+            self.index = (self.index + 1) % self.slides.len();
+            self.activate_slide()?;
+        }
+
+        let area = viewport.bounding_box();
+        self.slides[self.index].page.render(&mut viewport.viewport(&area))
+    }
+
+    fn deactivate_slide(&mut self) -> Result<(), DisplayError> {
+        match self.slides.get_mut(self.index) {
+            Some(slide) => slide.page.deactivated(),
+            None => Ok(()),
+        }
+    }
+
+    fn forward_input(&mut self, event: InputEvent) -> bool {
+        match self.slides.get_mut(self.index) {
+            Some(slide) => slide.page.input(event),
+            None => false,
+        }
+    }
+
+    fn fastest_refresh(&self) -> Refresh {
+        self.slides
+            .iter()
+            .map(|slide| slide.page.refresh())
+            .fold(SLIDE_CHECK_REFRESH, Refresh::fastest)
+    }
+}
+
+impl<'a, D, const PAGE_COUNT: usize> Default for Carousel<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D, const PAGE_COUNT: usize> Page<D> for Carousel<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.activate_slide()
+    }
+
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let area = display.bounding_box();
+        self.render_slide(&mut Viewport::new(display, &area))
+    }
+
+    fn deactivated(&mut self) -> Result<(), DisplayError> {
+        self.deactivate_slide()
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.fastest_refresh()
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        self.forward_input(event)
+    }
+}
+
+impl<'a, 'c, D, const PAGE_COUNT: usize> Page<Viewport<'c, D>> for Carousel<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.activate_slide()
+    }
+
+    fn render(&mut self, display: &mut Viewport<'c, D>) -> Result<(), DisplayError> {
+        self.render_slide(display)
+    }
+
+    fn deactivated(&mut self) -> Result<(), DisplayError> {
+        self.deactivate_slide()
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.fastest_refresh()
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        self.forward_input(event)
+    }
+}
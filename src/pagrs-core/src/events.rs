@@ -0,0 +1,66 @@
+use core::ptr::addr_of;
+use display_interface::DisplayError;
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::pubsub::{DynSubscriber, PubSubChannel};
+use embassy_time::Duration;
+use crate::fmt::trace;
+
+/// how many events are kept for each subscriber. Once full the oldest events are dropped.
+const EVENT_QUEUE_SIZE: usize = 8;
+/// the maximum amount of subscribers at the same time
+const MAX_SUBSCRIBERS: usize = 4;
+
+static mut EVENTS: PubSubChannel<NoopRawMutex, RotationEvent, EVENT_QUEUE_SIZE, MAX_SUBSCRIBERS, 0> = PubSubChannel::new();
+
+/// the stream of events published by the [PageRotator](crate::PageRotator).
+fn events() -> &'static PubSubChannel<NoopRawMutex, RotationEvent, EVENT_QUEUE_SIZE, MAX_SUBSCRIBERS, 0> {
+    // the channel is only ever accessed via shared references from within one executor
+    unsafe { &*addr_of!(EVENTS) }
+}
+
+/// something that happened in the [PageRotator](crate::PageRotator), received via [RotationEvents].
+///
+/// Pages are identified by their index, in order of registration. The rotator only holds references to the pages and
+/// knows no other name for them, so the index is their ID: it never changes while the rotator runs. Map it to your own
+/// IDs where needed, e.g. with an array in the order the pages are registered.
+///
+/// There is no separate kind of alert page: an alert is a page [pushed](crate::PageController::push) on top of the
+/// rotation, announced via [`RotationEvent::PagePushed`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RotationEvent {
+    /// the page got rotated in and is visible now
+    PageActivated { index: usize },
+    /// the page got rotated out
+    PageDeactivated { index: usize },
+    /// the page got [pushed](crate::PageController::push) on top of the page `onto`, e.g. to show an alert. Followed by
+    /// the [`PageActivated`](RotationEvent::PageActivated) of the page once it is visible.
+    PagePushed { index: usize, onto: usize },
+    /// the page with the given index is going to be shown in `after`, if the rotation isn't changed meanwhile.
+    /// Only published if enabled via [`notify_before_rotation`](crate::PageRotator::notify_before_rotation).
+    NextPageComing { index: usize, after: Duration },
+    /// the rotation got paused
+    Paused,
+    /// the rotation got resumed
+    Resumed,
+    /// showing the page with the given index failed, the rotation continues with the next page
+    DisplayError { index: usize, error: DisplayError },
+}
+
+/// receive the [events](RotationEvent) of the [PageRotator](crate::PageRotator), created by
+/// [`PageController::subscribe`](crate::PageController::subscribe).
+///
+/// Use `next_message_pure().await` to wait for the next event. A subscriber that doesn't keep up misses the oldest
+/// events, the rotation is never blocked by it.
+pub type RotationEvents = DynSubscriber<'static, RotationEvent>;
+
+/// create a new subscriber, `None` if the maximum amount of subscribers is reached
+pub(crate) fn subscribe() -> Option<RotationEvents> {
+    events().dyn_subscriber().ok()
+}
+
+/// send the event to all subscribers, without waiting for them
+pub(crate) fn publish(event: RotationEvent) {
+    trace!("rotation event: {:?}", event);
+    events().immediate_publisher().publish_immediate(event);
+}
@@ -0,0 +1,141 @@
+use display_interface::DisplayError;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::{PointsIter, Rectangle};
+use embedded_graphics_core::Pixel;
+use crate::orientation::Orientation;
+
+/// a monochrome, in-memory frame, used by backends which need to render a frame once and
+/// distribute it afterward (e.g. the [MirrorBackend](crate::MirrorBackend)).
+///
+/// The memory layout follows the one of the SSD1306: the frame is split into `PAGES` rows of
+/// 8 pixels height each, every byte holding one column of such a page (least significant bit on top).
+/// Drawing and reading pixels follows the [Orientation] of the frame, the memory layout stays the same.
+///
+/// ## type parameters
+/// - `WIDTH`: the width of the frame in pixels
+/// - `PAGES`: the height of the frame in pages of 8 pixels, e.g. `8` for a 64 pixel high display
+#[derive(Debug, Clone)]
+pub struct FrameBuffer<const WIDTH: usize, const PAGES: usize> {
+    buffer: [[u8; WIDTH]; PAGES],
+    orientation: Orientation,
+}
+
+impl<const WIDTH: usize, const PAGES: usize> FrameBuffer<WIDTH, PAGES> {
+    /// create a new, blank frame
+    pub const fn new() -> Self {
+        Self {
+            buffer: [[0; WIDTH]; PAGES],
+            orientation: Orientation::Rotate0,
+        }
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// rotate the content drawn from now on. Width and height of the frame are swapped for a portrait orientation.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    /// reset all pixels of the frame to [BinaryColor::Off]
+    pub fn clear_buffer(&mut self) {
+        self.buffer = [[0; WIDTH]; PAGES];
+    }
+
+    /// read the color of a single pixel. Returns `None` if the point lies outside the frame.
+    pub fn pixel(&self, point: Point) -> Option<BinaryColor> {
+        self.native_pixel(self.to_native(point)?)
+    }
+
+    /// change the color of a single pixel. Points outside the frame are ignored.
+    pub fn set_pixel(&mut self, point: Point, color: BinaryColor) {
+        if let Some(native) = self.to_native(point) {
+            self.set_native_pixel(native, color);
+        }
+    }
+
+    fn native_pixel(&self, point: Point) -> Option<BinaryColor> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        let byte = self.buffer[point.y as usize / 8][point.x as usize];
+        Some(BinaryColor::from(byte & (1 << (point.y % 8)) != 0))
+    }
+
+    fn set_native_pixel(&mut self, point: Point, color: BinaryColor) {
+        if !self.contains(point) {
+            return;
+        }
+
+        let byte = &mut self.buffer[point.y as usize / 8][point.x as usize];
+        let mask = 1 << (point.y % 8);
+        if color.is_on() {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// the raw content of the frame, one byte array per page
+    pub fn pages(&self) -> &[[u8; WIDTH]; PAGES] {
+        &self.buffer
+    }
+
+    /// draw the given `area` of this frame onto the `target`, placing the top left corner of the
+    /// area at the origin of the target. The area is taken from the frame as stored, regardless of its [Orientation].
+    pub fn draw_area<D: DrawTarget<Color = BinaryColor>>(&self, area: &Rectangle, target: &mut D) -> Result<(), D::Error> {
+        let colors = area.points().map(|point| self.native_pixel(point).unwrap_or(BinaryColor::Off));
+        target.fill_contiguous(&Rectangle::new(Point::zero(), area.size), colors)
+    }
+
+    /// the size of the frame as stored, regardless of its [Orientation]
+    pub const fn native_size(&self) -> Size {
+        Size::new(WIDTH as u32, (PAGES * 8) as u32)
+    }
+
+    /// the point in memory of a point of the oriented frame, `None` if it lies outside the frame
+    fn to_native(&self, point: Point) -> Option<Point> {
+        let size = self.size();
+        if point.x < 0 || point.y < 0 || point.x as u32 >= size.width || point.y as u32 >= size.height {
+            return None;
+        }
+
+        Some(self.orientation.to_native(point, self.native_size()))
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < WIDTH && (point.y as usize) < PAGES * 8
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> Default for FrameBuffer<WIDTH, PAGES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> OriginDimensions for FrameBuffer<WIDTH, PAGES> {
+    fn size(&self) -> Size {
+        self.orientation.size(self.native_size())
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> DrawTarget for FrameBuffer<WIDTH, PAGES> {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.set_pixel(point, color);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+/// an input of the user, e.g. of a button or a rotary encoder, forwarded by the [PageRotator](crate::PageRotator)
+/// to the active [Page](crate::Page) via [`Page::input`](crate::Page::input).
+///
+/// Events not consumed by the page are used for navigation: [`Up`](InputEvent::Up) and turning counter-clockwise
+/// show the previous page, [`Down`](InputEvent::Down) and turning clockwise show the next page.
+/// [`Select`](InputEvent::Select) drills down to the page returned by [`Page::drill_down`](crate::Page::drill_down),
+/// [`Back`](InputEvent::Back) returns from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InputEvent {
+    Up,
+    Down,
+    Select,
+    Back,
+    /// a rotary encoder was turned by the given amount of steps, positive for clockwise
+    Rotate(i8),
+}
@@ -1,17 +1,43 @@
 #![no_std]
 
 use display_interface::DisplayError;
+use embassy_time::Duration;
 use embedded_graphics_core::draw_target::DrawTarget;
 use embedded_graphics_core::pixelcolor::BinaryColor;
 
+#[doc(hidden)]
+pub mod fmt;
 mod splash_screen;
 mod page_wrapper;
 mod rotation;
+mod backend;
+mod frame_buffer;
+mod mirror;
+mod span;
+mod split_screen;
+mod viewport;
+mod carousel;
+mod state;
+mod storage;
+mod remote;
+mod capture;
+mod input;
+mod events;
+mod stats;
+mod refresh;
+mod order;
+mod orientation;
+mod scroll;
+mod alignment;
+mod scrolling_ssd1306;
 
 /// the default framerate, if not overwritten by a page.
 /// Uses the value used traditionally by most movies
 const DEFAULT_FRAMES_PER_SECOND: u8 = 24;
 
+/// the default duration a page is shown, if not overwritten when registering the page.
+const DEFAULT_PAGE_DURATION: Duration = Duration::from_secs(5);
+
 /// Definition of a page that can be rotated in to be shown by the [PageRotator].
 ///
 /// implementing structs MUST provide the [`render`](Page::render) method, while for the other methods default implementations exist.
@@ -22,6 +48,7 @@ const DEFAULT_FRAMES_PER_SECOND: u8 = 24;
 /// - everytime a page is rotated in the [`activated`](Page::activated) method is called.
 /// - as long as the page is active the [`render`](Page::render) method is called for each frame.
 /// - everytime a page is rotated out the [`deactivated`](Page::deactivated) method is called.
+/// - while the page is active it receives the [input events](InputEvent) via the [`input`](Page::input) method.
 pub trait Page<D: DrawTarget<Color = BinaryColor, Error = DisplayError>> {
     /// inform the page, that it is rotated in and will be visible on the display
     /// and should prepare internal state so it can be [`render`](Page::render)-ed.
@@ -30,7 +57,7 @@ pub trait Page<D: DrawTarget<Color = BinaryColor, Error = DisplayError>> {
     }
 
     /// draw the content of the page on the provided `display`.
-    /// The method is called as often per second as [`Page::frames_per_second`] defines
+    /// The method is called as often as [`Page::refresh`] defines
     ///
     /// parameter:
     /// `display`: a [DrawTarget] object that can be used to draw something on the display
@@ -44,9 +71,82 @@ pub trait Page<D: DrawTarget<Color = BinaryColor, Error = DisplayError>> {
 
     /// return the required framerate of that page.
     /// the value returned is how often the [`render`](Page::render) method will be called per second.
+    ///
+    /// **info**: only used by the default implementation of [`refresh`](Page::refresh). Implement that instead
+    /// for framerates below one frame per second or pages rendered only on demand.
     fn frames_per_second(&self) -> u8  {
         DEFAULT_FRAMES_PER_SECOND
     }
+
+    /// return how often the [`render`](Page::render) method is called, see [Refresh].
+    /// Defaults to the [`frames_per_second`](Page::frames_per_second) of the page.
+    fn refresh(&self) -> Refresh {
+        Refresh::FramesPerSecond(self.frames_per_second())
+    }
+
+    /// return if the display shall be cleared before each call to [`render`](Page::render).
+    /// Pages which only redraw the parts that changed return `false`, so the previous frame is kept.
+    /// The first frame after the page got [`activated`](Page::activated) always starts on a cleared display.
+    fn clear_each_frame(&self) -> bool {
+        true
+    }
+
+    /// handle an [InputEvent] while the page is active, e.g. to move a cursor.
+    /// Return `true` if the page consumed the event, otherwise the [PageRotator] uses it for navigation.
+    fn input(&mut self, _event: InputEvent) -> bool {
+        false
+    }
+
+    /// return the index of the page to drill down to on a [`Select`](InputEvent::Select) not consumed by the page,
+    /// e.g. a page with the details of an overview. The detail page returns to this page on [`Back`](InputEvent::Back),
+    /// see [`PageController::push`].
+    fn drill_down(&self) -> Option<usize> {
+        None
+    }
+
+    /// return a region of the display which scrolls continuously while the page is shown, e.g. a news ticker.
+    ///
+    /// **info**: backends scrolling in hardware (like the [ScrollingSsd1306]) move the content without any bus
+    /// traffic, the page is then only rendered again on demand, which restarts the scroll. Other backends scroll the
+    /// frame in software, rendering the page at least once per step of the scroll. That requires reading back the
+    /// frame, which a plain `Ssd1306Async` can't as the driver keeps its buffer private: drive the display via a
+    /// [ScrollingSsd1306] instead, otherwise the region doesn't move.
+    fn scroll(&self) -> Option<Scroll> {
+        None
+    }
+
+    /// return `true` to keep the page shown after its duration ran out, e.g. until a marquee completed a full pass.
+    /// Asked when the duration ran out and again after each frame, the rotation continues once it returns `false`.
+    ///
+    /// **info**: only the automatic rotation is held, navigating away via the [PageController] always works.
+    fn hold(&self) -> bool {
+        false
+    }
+
+    /// return how long the page needs to be shown, e.g. to flip through all of its screens.
+    /// Asked after each frame, the page is shown at least for the duration it was registered with.
+    fn required_duration(&self) -> Option<Duration> {
+        None
+    }
 }
 
-pub use rotation::{PageRotator, PageController};
\ No newline at end of file
+pub use rotation::{PageRotator, PageController, PageStatus, RotatorStatus};
+pub use backend::Backend;
+pub use frame_buffer::FrameBuffer;
+pub use orientation::Orientation;
+pub use alignment::{HorizontalAlignment, VerticalAlignment};
+pub use scroll::{Scroll, ScrollDirection, ScrollSpeed};
+pub use scrolling_ssd1306::ScrollingSsd1306;
+pub use mirror::MirrorBackend;
+pub use span::{SpanBackend, SpanLayout};
+pub use split_screen::{SplitLayout, SplitScreen};
+pub use viewport::Viewport;
+pub use carousel::Carousel;
+pub use input::InputEvent;
+pub use events::{RotationEvent, RotationEvents};
+pub use stats::FrameStats;
+pub use refresh::Refresh;
+pub use order::{Candidates, PingPong, RotationOrder, Sequential, Shuffle, Weighted};
+pub use storage::{MemoryStorage, NorFlashStorage, StateStorage, StorageError};
+pub use remote::{ParseError, RemoteCommand, RemoteControl, RemoteError};
+pub use capture::{capture_size, CaptureError, CapturedFrame};
@@ -0,0 +1,142 @@
+use display_interface::DisplayError;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{Dimensions, OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::Pixel;
+use crate::backend::{combined_result, Backend};
+use crate::frame_buffer::FrameBuffer;
+use crate::orientation::Orientation;
+
+/// a [Backend] showing the same content on several displays.
+///
+/// The pages render each frame once into an internal [FrameBuffer], which is then copied onto
+/// every display and flushed. As each display applies its own `DisplayRotation` while copying,
+/// e.g. a panel mounted upside down can simply be created with `DisplayRotation::Rotate180`.
+/// An [Orientation] set at runtime rotates the internal [FrameBuffer] instead, so it applies to all displays alike.
+///
+/// **info**: the frame is copied as stored, so every display must have the size of the [FrameBuffer] regardless of its
+/// [Orientation]. A panel created with `DisplayRotation::Rotate90` or `Rotate270` swaps width and height, it can only be
+/// mirrored together with other portrait panels onto a portrait frame (e.g. `64, 16` for 128x64 displays).
+/// [`new`](MirrorBackend::new) panics on displays of another size.
+///
+/// The displays are isolated from each other: if one display fails to initialize or to flush,
+/// the others still get updated. Only if _all_ displays fail the error is passed on to the
+/// [PageRotator](crate::PageRotator). The last error per display can be checked via
+/// [`display_error`](MirrorBackend::display_error).
+///
+/// ## type parameters
+/// - `D`: the type of the displays, any [Backend] (e.g. a buffered `Ssd1306Async`)
+/// - `COUNT`: the amount of displays
+/// - `WIDTH`, `PAGES`: the dimension of the internal [FrameBuffer], should match the displays
+///
+/// ## example
+/// ```rust,ignore
+/// let front = Ssd1306Async::new(front_interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// let back = Ssd1306Async::new(back_interface, DisplaySize128x64, DisplayRotation::Rotate180)
+///     .into_buffered_graphics_mode();
+///
+/// let mirror = MirrorBackend::<_, 2, 128, 8>::new([front, back]);
+/// let mut pagr = PageRotator::<5, _>::new(mirror);
+/// ```
+pub struct MirrorBackend<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> {
+    displays: [D; COUNT],
+    errors: [Option<DisplayError>; COUNT],
+    frame: FrameBuffer<WIDTH, PAGES>,
+}
+
+impl<D: Dimensions, const COUNT: usize, const WIDTH: usize, const PAGES: usize> MirrorBackend<D, COUNT, WIDTH, PAGES> {
+    /// create a new [MirrorBackend] for the given displays, which all must have the size of the frame
+    pub fn new(displays: [D; COUNT]) -> Self {
+        let frame = FrameBuffer::new();
+        // a display of another size, e.g. one rotated by 90°, would show the frame clipped
+        assert!(
+            displays.iter().all(|display| display.bounding_box().size == frame.native_size()),
+            "the displays of a MirrorBackend must have the size of its frame"
+        );
+
+        Self {
+            displays,
+            errors: core::array::from_fn(|_| None),
+            frame,
+        }
+    }
+
+    /// the error of the last operation on the display with the given index, `None` if it succeeded
+    pub fn display_error(&self, index: usize) -> Option<&DisplayError> {
+        self.errors.get(index).and_then(Option::as_ref)
+    }
+}
+
+impl<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> OriginDimensions for MirrorBackend<D, COUNT, WIDTH, PAGES> {
+    fn size(&self) -> Size {
+        self.frame.size()
+    }
+}
+
+impl<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> DrawTarget for MirrorBackend<D, COUNT, WIDTH, PAGES> {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.frame.draw_iter(pixels)
+    }
+}
+
+impl<D: Backend, const COUNT: usize, const WIDTH: usize, const PAGES: usize> Backend for MirrorBackend<D, COUNT, WIDTH, PAGES> {
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.init().await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    fn clear_buffer(&mut self) {
+        self.frame.clear_buffer();
+    }
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        let area = Rectangle::new(Point::zero(), self.frame.native_size());
+
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            display.clear_buffer();
+            let result = match self.frame.draw_area(&area, display) {
+                Ok(()) => display.flush().await,
+                Err(e) => Err(e),
+            };
+            *error = result.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.set_brightness(brightness).await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), DisplayError> {
+        self.frame.set_orientation(orientation);
+        Ok(())
+    }
+
+    async fn set_inverted(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.set_inverted(inverted).await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    fn read_pixel(&self, point: Point) -> Option<BinaryColor> {
+        self.frame.pixel(point)
+    }
+}
@@ -0,0 +1,247 @@
+/// the pages a [RotationOrder] chooses from: all registered pages, some of them disabled via the
+/// [PageController](crate::PageController)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Candidates {
+    count: usize,
+    disabled: u32,
+}
+
+impl Candidates {
+    /// create new [Candidates] of `count` pages, `disabled` being a bit mask of the disabled pages among the first 32
+    pub const fn new(count: usize, disabled: u32) -> Self {
+        Self { count, disabled }
+    }
+
+    /// the amount of registered pages, enabled or not
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// if the page with the given index exists and is not disabled
+    pub fn is_enabled(&self, index: usize) -> bool {
+        index < self.count && (index >= 32 || self.disabled & (1 << index) == 0)
+    }
+
+    /// the indices of all pages which are not disabled, in order of registration
+    pub fn enabled(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.count).filter(|index| self.is_enabled(*index))
+    }
+
+    /// the first enabled page after `index` in order of registration, wrapping around.
+    /// `index` itself is only returned if it is the only page enabled.
+    pub fn after(&self, index: usize) -> Option<usize> {
+        (1..=self.count)
+            .map(|step| (index + step) % self.count)
+            .find(|candidate| self.is_enabled(*candidate))
+    }
+
+    /// the first enabled page before `index` in order of registration, wrapping around.
+    /// `index` itself is only returned if it is the only page enabled.
+    pub fn before(&self, index: usize) -> Option<usize> {
+        (1..=self.count)
+            .map(|step| (index + self.count - step % self.count) % self.count)
+            .find(|candidate| self.is_enabled(*candidate))
+    }
+}
+
+/// decides which page the [PageRotator](crate::PageRotator) shows next, set via
+/// [`set_order`](crate::PageRotator::set_order).
+///
+/// Going back to the previous page is not part of the order: the [PageRotator](crate::PageRotator) returns to the
+/// pages in the order they were actually shown.
+///
+/// Built-in orders are [Sequential], [Weighted], [Shuffle] and [PingPong].
+pub trait RotationOrder {
+    /// the index of the page to show after the `current` one, which must be enabled in the `candidates`.
+    /// `None` keeps showing the current page.
+    fn next(&mut self, current: usize, candidates: &Candidates) -> Option<usize>;
+}
+
+/// show the pages in order of registration, starting over after the last one. The default order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sequential;
+
+impl RotationOrder for Sequential {
+    fn next(&mut self, current: usize, candidates: &Candidates) -> Option<usize> {
+        candidates.after(current)
+    }
+}
+
+/// show some pages more often than others, spreading the repetitions evenly over the rotation.
+///
+/// ## type parameters
+/// - `PAGE_COUNT`: the amount of weights, pages without a weight are never shown
+///
+/// ## example
+/// the promo page (0) is shown three times as often as each of the other pages:
+/// ```rust
+/// use pagrs_core::{Candidates, RotationOrder, Weighted};
+///
+/// let mut order = Weighted::new([3, 1, 1]);
+/// let candidates = Candidates::new(3, 0);
+///
+/// let mut current = 0;
+/// let shown: Vec<usize> = (0..5).map(|_| {
+///     current = order.next(current, &candidates).unwrap();
+///     current
+/// }).collect();
+/// assert_eq!(shown, [0, 1, 0, 2, 0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Weighted<const PAGE_COUNT: usize> {
+    weights: [u8; PAGE_COUNT],
+    credits: [i32; PAGE_COUNT],
+}
+
+impl<const PAGE_COUNT: usize> Weighted<PAGE_COUNT> {
+    /// create a new [Weighted] order, showing each page as often relative to the others as its weight says.
+    /// Pages with a weight of 0 are never shown.
+    pub const fn new(weights: [u8; PAGE_COUNT]) -> Self {
+        Self {
+            weights,
+            credits: [0; PAGE_COUNT],
+        }
+    }
+}
+
+impl<const PAGE_COUNT: usize> RotationOrder for Weighted<PAGE_COUNT> {
+    fn next(&mut self, _current: usize, candidates: &Candidates) -> Option<usize> {
+        // smooth weighted round robin: every page earns its weight each turn, the richest one is shown and pays for it
+        let mut total = 0;
+        let mut richest: Option<usize> = None;
+        for index in candidates.enabled().take_while(|index| *index < PAGE_COUNT) {
+            let weight = self.weights[index] as i32;
+            if weight == 0 {
+                continue;
+            }
+
+            total += weight;
+            self.credits[index] += weight;
+            if richest.is_none_or(|richest| self.credits[index] > self.credits[richest]) {
+                richest = Some(index);
+            }
+        }
+
+        let shown = richest?;
+        self.credits[shown] -= total;
+        Some(shown)
+    }
+}
+
+/// show the pages in random order: each round every page is shown once, and a page is never shown twice in a row.
+/// A new round starts with the pages other than the one shown last.
+///
+/// ## type parameters
+/// - `PAGE_COUNT`: the maximum amount of pages, pages beyond it are never shown
+///
+/// ## example
+/// ```rust
+/// use pagrs_core::{Candidates, RotationOrder, Shuffle};
+///
+/// let mut order = Shuffle::<4>::new(42);
+/// let candidates = Candidates::new(4, 0);
+///
+/// let mut current = 0;
+/// let mut shown = [false; 4];
+/// for _ in 0..6 {
+///     let next = order.next(current, &candidates).unwrap();
+///     assert_ne!(next, current);
+///     shown[next] = true;
+///     current = next;
+/// }
+/// assert_eq!(shown, [true; 4]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Shuffle<const PAGE_COUNT: usize> {
+    random: u64,
+    shown: [bool; PAGE_COUNT],
+}
+
+impl<const PAGE_COUNT: usize> Shuffle<PAGE_COUNT> {
+    /// create a new [Shuffle] order. Use a different `seed` on each start to get a different order, e.g. from a
+    /// hardware random number generator.
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            // xorshift never leaves a state of 0
+            random: if seed == 0 { 0x2545_f491_4f6c_dd1d } else { seed },
+            shown: [false; PAGE_COUNT],
+        }
+    }
+
+    /// the next number of the xorshift64 generator
+    fn random(&mut self) -> u64 {
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 7;
+        self.random ^= self.random << 17;
+        self.random
+    }
+
+    fn remaining<'c>(&'c self, current: usize, candidates: &'c Candidates) -> impl Iterator<Item = usize> + 'c {
+        candidates
+            .enabled()
+            .take_while(|index| *index < PAGE_COUNT)
+            .filter(move |index| *index != current && !self.shown[*index])
+    }
+}
+
+impl<const PAGE_COUNT: usize> RotationOrder for Shuffle<PAGE_COUNT> {
+    fn next(&mut self, current: usize, candidates: &Candidates) -> Option<usize> {
+        let mut remaining = self.remaining(current, candidates).count();
+        if remaining == 0 {
+            // start a new round, the current page still has to wait for another page first
+            self.shown = [false; PAGE_COUNT];
+            remaining = self.remaining(current, candidates).count();
+        }
+
+        if remaining == 0 {
+            return candidates.is_enabled(current).then_some(current);
+        }
+
+        let pick = (self.random() % remaining as u64) as usize;
+        let next = self.remaining(current, candidates).nth(pick)?;
+        self.shown[next] = true;
+        Some(next)
+    }
+}
+
+/// walk through the pages from the first to the last one and back again, e.g. `0 1 2 1 0 1 2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PingPong {
+    forward: bool,
+}
+
+impl PingPong {
+    pub const fn new() -> Self {
+        Self { forward: true }
+    }
+}
+
+impl Default for PingPong {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RotationOrder for PingPong {
+    fn next(&mut self, current: usize, candidates: &Candidates) -> Option<usize> {
+        let ahead = candidates.enabled().find(|index| *index > current);
+        let behind = candidates.enabled().filter(|index| *index < current).last();
+
+        let next = match (self.forward, ahead, behind) {
+            (true, Some(next), _) | (false, _, Some(next)) => next,
+            // turn around at the end
+            (true, None, Some(next)) | (false, Some(next), None) => {
+                self.forward = !self.forward;
+                next
+            }
+            (_, None, None) => return candidates.is_enabled(current).then_some(current),
+        };
+
+        Some(next)
+    }
+}
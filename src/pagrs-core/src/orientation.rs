@@ -0,0 +1,65 @@
+use embedded_graphics_core::geometry::{Point, Size};
+use ssd1306::rotation::DisplayRotation;
+
+/// how the content is rotated on the display, clockwise, as set via
+/// [`PageController::set_orientation`](crate::PageController::set_orientation).
+///
+/// ## example
+/// ```rust
+/// use embedded_graphics_core::geometry::{Point, Size};
+/// use pagrs_core::Orientation;
+///
+/// // a 128x64 display held upright shows a 64x128 canvas
+/// assert_eq!(Orientation::Rotate90.size(Size::new(128, 64)), Size::new(64, 128));
+///
+/// // whose top left corner is the top right corner of the display
+/// assert_eq!(Orientation::Rotate90.to_native(Point::new(0, 0), Size::new(128, 64)), Point::new(127, 0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Orientation {
+    #[default]
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+impl Orientation {
+    /// if width and height of the display are swapped in this orientation
+    pub const fn is_portrait(&self) -> bool {
+        matches!(self, Orientation::Rotate90 | Orientation::Rotate270)
+    }
+
+    /// the size of the canvas of a display of the `native` size, in this orientation
+    pub const fn size(&self, native: Size) -> Size {
+        if self.is_portrait() {
+            Size::new(native.height, native.width)
+        } else {
+            native
+        }
+    }
+
+    /// the point of a display of the `native` size, which shows the `point` of the canvas in this orientation
+    pub const fn to_native(&self, point: Point, native: Size) -> Point {
+        let width = native.width as i32;
+        let height = native.height as i32;
+        match self {
+            Orientation::Rotate0 => point,
+            Orientation::Rotate90 => Point::new(width - 1 - point.y, point.x),
+            Orientation::Rotate180 => Point::new(width - 1 - point.x, height - 1 - point.y),
+            Orientation::Rotate270 => Point::new(point.y, height - 1 - point.x),
+        }
+    }
+}
+
+impl From<Orientation> for DisplayRotation {
+    fn from(orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Rotate0 => DisplayRotation::Rotate0,
+            Orientation::Rotate90 => DisplayRotation::Rotate90,
+            Orientation::Rotate180 => DisplayRotation::Rotate180,
+            Orientation::Rotate270 => DisplayRotation::Rotate270,
+        }
+    }
+}
@@ -1,65 +1,60 @@
-use core::cell::RefCell;
-use display_interface::{AsyncWriteOnlyDataCommand, DisplayError};
-use embassy_time::{Duration, Ticker};
-use ssd1306::mode::BufferedGraphicsModeAsync;
-use ssd1306::size::DisplaySizeAsync;
-use ssd1306::Ssd1306Async;
-use crate::{Page, DEFAULT_FRAMES_PER_SECOND};
-
-pub(crate) struct PageWrapper<'a, DI, SIZE, MODE> {
-    page: RefCell<&'a mut dyn Page<Ssd1306Async<DI, SIZE, MODE>>>,
-    pub custom_duration: Option<Duration>
+use core::cell::{Cell, RefCell, RefMut};
+use embassy_time::Duration;
+use crate::backend::Backend;
+use crate::stats::FrameStats;
+use crate::{Page, DEFAULT_PAGE_DURATION};
+
+pub(crate) struct PageWrapper<'a, B> {
+    page: RefCell<&'a mut dyn Page<B>>,
+    custom_duration: Cell<Option<Duration>>,
+    stats: Cell<FrameStats>,
 }
 
-impl<'a, DI, SIZE, MODE> PageWrapper<'a, DI, SIZE, MODE> {
-    pub(crate) fn new(page: &'a mut dyn Page<Ssd1306Async<DI, SIZE, MODE>>) -> Self {
+impl<'a, B> PageWrapper<'a, B> {
+    pub(crate) fn new(page: &'a mut dyn Page<B>) -> Self {
         Self {
             page: RefCell::new(page),
-            custom_duration: None
+            custom_duration: Cell::new(None),
+            stats: Cell::new(FrameStats::default()),
         }
     }
 
-    pub(crate) fn with_custom_duration(page: &'a mut dyn Page<Ssd1306Async<DI, SIZE, MODE>>, duration: Duration) -> Self {
+    pub(crate) fn with_custom_duration(page: &'a mut dyn Page<B>, duration: Duration) -> Self {
         Self {
             page: RefCell::new(page),
-            custom_duration: Some(duration)
+            custom_duration: Cell::new(Some(duration)),
+            stats: Cell::new(FrameStats::default()),
         }
     }
-}
 
-impl<'a, DI, SIZE> PageWrapper<'a, DI, SIZE, BufferedGraphicsModeAsync<SIZE>>
-where
-    DI: AsyncWriteOnlyDataCommand,
-    SIZE: DisplaySizeAsync,
-{
-    pub(crate) async fn take_over<F: Fn() -> bool> (
-        &self,
-        display: &mut Ssd1306Async<DI, SIZE, BufferedGraphicsModeAsync<SIZE>>,
-        cancel: F,
-    ) -> Result<(), DisplayError> {
-        let mut page = self.page.borrow_mut();
-        let mut frames_per_second = page.frames_per_second();
-        // a value of 0 for frames per second can't be allowed. resetting it to a default
-        if frames_per_second == 0 {
-            frames_per_second = DEFAULT_FRAMES_PER_SECOND;
-        }
-        let mut ticker = Ticker::every(Duration::from_millis(1000 / frames_per_second as u64));
+    pub(crate) fn page(&self) -> RefMut<'_, &'a mut dyn Page<B>> {
+        self.page.borrow_mut()
+    }
 
-        page.activated()?;
-        loop {
-            display.clear_buffer();
-            page.render(display)?;
-            display.flush().await?;
+    /// how long the page is shown before rotating to the next one
+    pub(crate) fn duration(&self) -> Duration {
+        self.custom_duration.get().unwrap_or(DEFAULT_PAGE_DURATION)
+    }
 
-            let shall_cancel = cancel();
-            if shall_cancel {
-                break;
-            }
+    pub(crate) fn set_duration(&self, duration: Duration) {
+        self.custom_duration.set(Some(duration));
+    }
 
-            ticker.next().await;
-        }
-        page.deactivated()?;
+    /// the timing statistics of all frames shown of the page
+    pub(crate) fn stats(&self) -> FrameStats {
+        self.stats.get()
+    }
+
+    pub(crate) fn update_stats(&self, update: impl FnOnce(&mut FrameStats)) {
+        let mut stats = self.stats.get();
+        update(&mut stats);
+        self.stats.set(stats);
+    }
+}
 
-        Ok(())
+impl<'a, B: Backend> PageWrapper<'a, B> {
+    /// the time between two frames, according to the refresh of the page. `None` if rendered only on demand
+    pub(crate) fn frame_interval(&self) -> Option<Duration> {
+        self.page.borrow().refresh().interval()
     }
 }
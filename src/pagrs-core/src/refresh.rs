@@ -0,0 +1,63 @@
+use embassy_time::Duration;
+use crate::DEFAULT_FRAMES_PER_SECOND;
+
+/// the shortest time between two frames, so a page can't keep the executor busy
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// how often a [Page](crate::Page) is rendered, as returned by [`Page::refresh`](crate::Page::refresh).
+///
+/// ## example
+/// ```rust
+/// use embassy_time::Duration;
+/// use pagrs_core::Refresh;
+///
+/// // 24 frames per second are exactly 41.666 ms apart, not 41 ms
+/// assert_eq!(Refresh::FramesPerSecond(24).interval(), Some(Duration::from_hz(24)));
+///
+/// // a clock showing minutes only needs to redraw every 10 seconds
+/// assert_eq!(Refresh::Every(Duration::from_secs(10)).interval(), Some(Duration::from_secs(10)));
+///
+/// // 2.5 frames per second
+/// assert_eq!(Refresh::frames_per(5, Duration::from_secs(2)).interval(), Some(Duration::from_millis(400)));
+///
+/// // a static page is only rendered when needed
+/// assert_eq!(Refresh::OnDemand.interval(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Refresh {
+    /// render the given amount of frames each second. A value of `0` is replaced by the default of 24.
+    FramesPerSecond(u8),
+    /// render a frame each time the given time passed
+    Every(Duration),
+    /// render only when the page got [`activated`](crate::Page::activated), after an [input](crate::InputEvent) or
+    /// when requested via [`PageController::redraw`](crate::PageController::redraw)
+    OnDemand,
+}
+
+impl Refresh {
+    /// render `frames` frames during each `period`, e.g. for framerates below one or not a whole number per second
+    pub const fn frames_per(frames: u32, period: Duration) -> Self {
+        let frames = if frames == 0 { 1 } else { frames as u64 };
+        Refresh::Every(Duration::from_ticks(period.as_ticks() / frames))
+    }
+
+    /// the time between two frames, `None` if rendered only on demand
+    pub fn interval(&self) -> Option<Duration> {
+        match *self {
+            Refresh::FramesPerSecond(0) => Some(Duration::from_hz(DEFAULT_FRAMES_PER_SECOND as u64)),
+            Refresh::FramesPerSecond(frames_per_second) => Some(Duration::from_hz(frames_per_second as u64)),
+            Refresh::Every(interval) => Some(interval.max(MIN_INTERVAL)),
+            Refresh::OnDemand => None,
+        }
+    }
+
+    /// the more frequent of both, e.g. for a page showing several child pages at once
+    pub(crate) fn fastest(self, other: Refresh) -> Refresh {
+        match (self.interval(), other.interval()) {
+            (Some(interval), Some(other_interval)) => Refresh::Every(interval.min(other_interval)),
+            (Some(_), None) => self,
+            (None, _) => other,
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use core::fmt::Write as _;
+use embassy_time::Duration;
+use embedded_graphics_core::geometry::{Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_io_async::{Read, Write};
+use heapless::{String, Vec};
+use crate::capture::{capture_size, CaptureError};
+use crate::rotation::PageController;
+use crate::fmt::debug;
+
+/// the maximum length of a command line, longer lines are rejected
+const MAX_LINE_LENGTH: usize = 32;
+/// the maximum length of a single line of a response
+const MAX_RESPONSE_LENGTH: usize = 48;
+/// the largest frame the `capture` command can send: a 128x64 display
+const MAX_CAPTURE_SIZE: usize = capture_size(Size::new(128, 64));
+
+/// errors of parsing a line of the remote protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseError {
+    /// the line contains no command
+    Empty,
+    /// the command is not known
+    UnknownCommand,
+    /// the command requires more arguments
+    MissingArgument,
+    /// an argument is not a valid number
+    InvalidNumber,
+    /// the line contains more arguments than the command takes
+    TooManyArguments,
+}
+
+impl ParseError {
+    fn message(&self) -> &'static str {
+        match self {
+            ParseError::Empty => "empty command",
+            ParseError::UnknownCommand => "unknown command, try 'help'",
+            ParseError::MissingArgument => "missing argument",
+            ParseError::InvalidNumber => "invalid number",
+            ParseError::TooManyArguments => "too many arguments",
+        }
+    }
+}
+
+/// errors ending the [RemoteControl]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RemoteError<R, W> {
+    /// reading from the connection failed
+    Read(R),
+    /// writing to the connection failed
+    Write(W),
+}
+
+/// a single command of the remote protocol, see [RemoteControl] for the syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RemoteCommand {
+    Help,
+    Status,
+    List,
+    Next,
+    Previous,
+    JumpTo(usize),
+    Pause,
+    Resume,
+    SetDuration { page: usize, millis: u64 },
+    SetBrightness(u8),
+    Enable(usize),
+    Disable(usize),
+    Capture,
+}
+
+impl RemoteCommand {
+    /// parse a single line of the remote protocol, without the line ending.
+    ///
+    /// ## example
+    /// ```rust
+    /// use pagrs_core::{ParseError, RemoteCommand};
+    ///
+    /// assert_eq!(RemoteCommand::parse("jump 2"), Ok(RemoteCommand::JumpTo(2)));
+    /// assert_eq!(RemoteCommand::parse("  duration 1 2500 "), Ok(RemoteCommand::SetDuration { page: 1, millis: 2500 }));
+    /// assert_eq!(RemoteCommand::parse("jump"), Err(ParseError::MissingArgument));
+    /// assert_eq!(RemoteCommand::parse("brightness 300"), Err(ParseError::InvalidNumber));
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let mut words = line.split_ascii_whitespace();
+        let command = match words.next().ok_or(ParseError::Empty)? {
+            "help" | "?" => RemoteCommand::Help,
+            "status" => RemoteCommand::Status,
+            "list" => RemoteCommand::List,
+            "next" => RemoteCommand::Next,
+            "prev" | "previous" => RemoteCommand::Previous,
+            "jump" => RemoteCommand::JumpTo(Self::argument(&mut words)?),
+            "pause" => RemoteCommand::Pause,
+            "resume" => RemoteCommand::Resume,
+            "duration" => RemoteCommand::SetDuration {
+                page: Self::argument(&mut words)?,
+                millis: Self::argument(&mut words)?,
+            },
+            "brightness" => RemoteCommand::SetBrightness(Self::argument(&mut words)?),
+            "enable" => RemoteCommand::Enable(Self::argument(&mut words)?),
+            "disable" => RemoteCommand::Disable(Self::argument(&mut words)?),
+            "capture" => RemoteCommand::Capture,
+            _ => return Err(ParseError::UnknownCommand),
+        };
+
+        if words.next().is_some() {
+            return Err(ParseError::TooManyArguments);
+        }
+
+        Ok(command)
+    }
+
+    fn argument<'l, T: core::str::FromStr>(words: &mut impl Iterator<Item = &'l str>) -> Result<T, ParseError> {
+        words
+            .next()
+            .ok_or(ParseError::MissingArgument)?
+            .parse()
+            .map_err(|_| ParseError::InvalidNumber)
+    }
+}
+
+/// drive the [PageRotator](crate::PageRotator) remotely via a line based text protocol, e.g. over the debug UART.
+///
+/// The [RemoteControl] works on any [embedded_io_async] reader and writer, so the same code runs on a UART,
+/// a USB serial or an in-memory pipe for testing. Each line holds one command, every command is answered with
+/// `OK` or `ERR <reason>`, optionally preceded by lines of output.
+///
+/// commands:
+/// - `help`: list the commands
+/// - `status`: show the current page, whether the rotation is paused and the brightness
+/// - `list`: show all pages with their index, duration in milliseconds and whether they are enabled
+/// - `next` / `prev`: cycle to the next or previous page
+/// - `jump <page>`: show the page with the given index
+/// - `pause` / `resume`: stop and continue the rotation
+/// - `duration <page> <millis>`: change how long a page is shown
+/// - `brightness <0-255>`: change the brightness of the display
+/// - `enable <page>` / `disable <page>`: add or remove a page from the rotation
+/// - `capture`: send the next frame as plain PBM (`P1`), lit pixels being white. Frames of up to 8192 pixels, e.g.
+///   128x64 or 256x32, larger ones are answered with `ERR frame too large`.
+///
+/// **info**: `status`, `list`, `jump`, `duration` and `capture` query the [PageRotator](crate::PageRotator) and only get
+/// answered once it is rotating.
+///
+/// ## example
+/// ```rust
+/// use pagrs_core::RemoteControl;
+///
+/// let input = b"next\nfoo\n";
+/// let mut output = [0u8; 64];
+///
+/// let mut remote = RemoteControl::new(&input[..], &mut output[..]);
+/// embassy_futures::block_on(remote.run()).unwrap();
+/// drop(remote);
+///
+/// assert!(output.starts_with(b"OK\r\nERR unknown command, try 'help'\r\n"));
+/// ```
+pub struct RemoteControl<R, W> {
+    reader: R,
+    writer: W,
+    controller: PageController,
+    line: Vec<u8, MAX_LINE_LENGTH>,
+    overflow: bool,
+}
+
+impl<R: Read, W: Write> RemoteControl<R, W> {
+    /// create a new [RemoteControl] reading commands from `reader` and answering on `writer`
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            controller: PageController::new(),
+            line: Vec::new(),
+            overflow: false,
+        }
+    }
+
+    /// give back the reader and writer
+    pub fn release(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+
+    /// process commands until the reader reaches its end or the connection fails
+    pub async fn run(&mut self) -> Result<(), RemoteError<R::Error, W::Error>> {
+        let mut chunk = [0u8; 16];
+        loop {
+            let length = self.reader.read(&mut chunk).await.map_err(RemoteError::Read)?;
+            if length == 0 {
+                return Ok(());
+            }
+
+            for &byte in &chunk[..length] {
+                self.receive(byte).await.map_err(RemoteError::Write)?;
+            }
+        }
+    }
+
+    /// parse and execute a single line, writing the response
+    pub async fn process_line(&mut self, line: &str) -> Result<(), W::Error> {
+        match RemoteCommand::parse(line) {
+            Ok(command) => self.execute(command).await,
+            Err(ParseError::Empty) => Ok(()),
+            Err(error) => {
+                debug!("rejected remote command {:?}: {:?}", line, error);
+                self.error(error.message()).await
+            }
+        }
+    }
+
+    /// execute a single command, writing the response
+    pub async fn execute(&mut self, command: RemoteCommand) -> Result<(), W::Error> {
+        debug!("remote command {:?}", command);
+        match command {
+            RemoteCommand::Help => {
+                self.writer.write_all(b"status | list | next | prev | jump <page> | pause | resume\r\n").await?;
+                self.writer.write_all(b"duration <page> <millis> | brightness <0-255> | enable <page> | disable <page>\r\n").await?;
+                self.writer.write_all(b"capture\r\n").await?;
+            }
+            RemoteCommand::Status => {
+                let status = self.controller.status().await;
+                let mut response = String::<MAX_RESPONSE_LENGTH>::new();
+                let _ = write!(
+                    response,
+                    "page {}/{} {}",
+                    status.current_page,
+                    status.page_count,
+                    if status.paused { "paused" } else { "rotating" }
+                );
+                if let Some(brightness) = status.brightness {
+                    let _ = write!(response, " brightness {}", brightness);
+                }
+                self.write_line(&response).await?;
+            }
+            RemoteCommand::List => {
+                let status = self.controller.status().await;
+                for index in 0..status.page_count {
+                    let Some(page) = self.controller.page_status(index).await else {
+                        continue;
+                    };
+
+                    let mut response = String::<MAX_RESPONSE_LENGTH>::new();
+                    let _ = write!(
+                        response,
+                        "{} {}ms {}{}",
+                        index,
+                        page.duration.as_millis(),
+                        if page.enabled { "enabled" } else { "disabled" },
+                        if index == status.current_page { " *" } else { "" }
+                    );
+                    self.write_line(&response).await?;
+                }
+            }
+            RemoteCommand::Next => self.controller.next().await,
+            RemoteCommand::Previous => self.controller.previous().await,
+            RemoteCommand::JumpTo(index) => {
+                if self.controller.page_status(index).await.is_none() {
+                    return self.error("no such page").await;
+                }
+                self.controller.jump_to(index).await;
+            }
+            RemoteCommand::Pause => self.controller.pause().await,
+            RemoteCommand::Resume => self.controller.resume().await,
+            RemoteCommand::SetDuration { page, millis } => {
+                if millis == 0 {
+                    return self.error("duration must not be 0").await;
+                }
+                if self.controller.page_status(page).await.is_none() {
+                    return self.error("no such page").await;
+                }
+                self.controller.set_page_duration(page, Duration::from_millis(millis)).await;
+            }
+            RemoteCommand::SetBrightness(brightness) => self.controller.set_brightness(brightness).await,
+            RemoteCommand::Enable(index) => self.controller.enable_page(index).await,
+            RemoteCommand::Disable(index) => self.controller.disable_page(index).await,
+            RemoteCommand::Capture => {
+                let mut buffer = [0; MAX_CAPTURE_SIZE];
+                let frame = match self.controller.capture(&mut buffer).await {
+                    Ok(frame) => frame,
+                    Err(CaptureError::Unsupported) => return self.error("capture not supported by the display").await,
+                    Err(CaptureError::BufferTooSmall) => return self.error("frame too large").await,
+                };
+
+                let size = frame.size();
+                let mut header = String::<MAX_RESPONSE_LENGTH>::new();
+                let _ = write!(header, "P1\r\n{} {}", size.width, size.height);
+                self.write_line(&header).await?;
+
+                // in PBM a set pixel is black. Rows wider than 128 pixels are written in several pieces
+                let mut row = [0u8; 128];
+                let width = size.width as usize;
+                for y in 0..size.height as i32 {
+                    for start in (0..width).step_by(row.len()) {
+                        let piece = &mut row[..(width - start).min(128)];
+                        for (offset, target) in piece.iter_mut().enumerate() {
+                            let lit = frame.pixel(Point::new((start + offset) as i32, y)) == Some(BinaryColor::On);
+                            *target = if lit { b'0' } else { b'1' };
+                        }
+                        self.writer.write_all(piece).await?;
+                    }
+                    self.writer.write_all(b"\r\n").await?;
+                }
+            }
+        }
+
+        self.write_line("OK").await
+    }
+
+    async fn receive(&mut self, byte: u8) -> Result<(), W::Error> {
+        if byte != b'\n' && byte != b'\r' {
+            if self.line.push(byte).is_err() {
+                self.overflow = true;
+            }
+            return Ok(());
+        }
+
+        let line = core::mem::take(&mut self.line);
+        if core::mem::take(&mut self.overflow) {
+            return self.error("line too long").await;
+        }
+
+        match core::str::from_utf8(&line) {
+            Ok(line) => self.process_line(line).await,
+            Err(_) => self.error("invalid characters").await,
+        }
+    }
+
+    async fn error(&mut self, message: &str) -> Result<(), W::Error> {
+        self.writer.write_all(b"ERR ").await?;
+        self.write_line(message).await
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<(), W::Error> {
+        self.writer.write_all(line.as_bytes()).await?;
+        self.writer.write_all(b"\r\n").await
+    }
+}
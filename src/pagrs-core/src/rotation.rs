@@ -1,28 +1,165 @@
-use core::cell::{RefCell};
-use core::mem::MaybeUninit;
-use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
-use display_interface::{AsyncWriteOnlyDataCommand, DisplayError};
+use core::cell::{Cell, RefCell};
+use core::ptr::addr_of;
+use display_interface::DisplayError;
 use embassy_executor::Spawner;
-use embassy_futures::select::select;
+use embassy_futures::select::{select3, Either3};
 use embassy_sync::blocking_mutex::raw::NoopRawMutex;
-use embassy_sync::channel::{Channel, Receiver};
-use embassy_time::{Duration, Timer};
-use heapless::Vec;
-use ssd1306::mode::{BufferedGraphicsModeAsync, DisplayConfigAsync};
-use ssd1306::size::DisplaySizeAsync;
-use ssd1306::{Ssd1306Async};
-use crate::Page;
+use embassy_sync::channel::Channel;
+use embassy_sync::mutex::{Mutex, MutexGuard};
+use embassy_sync::signal::Signal;
+use embassy_time::{Duration, Instant, Timer};
+use embedded_graphics_core::geometry::{Point, Size};
+use heapless::{Deque, Vec};
+use crate::backend::Backend;
+use crate::{InputEvent, Page};
+use crate::events::{publish, subscribe, RotationEvent, RotationEvents};
+use crate::capture::{capture_size, pack_byte, CaptureError, CapturedFrame};
+use crate::order::{Candidates, RotationOrder, Sequential};
+use crate::orientation::Orientation;
 use crate::page_wrapper::PageWrapper;
+use crate::scroll::scroll_in_software;
 use crate::splash_screen::SplashScreen;
+use crate::state::RotationState;
+use crate::stats::FrameStats;
+use crate::storage::StateStorage;
+use crate::fmt::{debug, error, info, trace, warn};
 
-static CANCEL_PAGE: AtomicBool = AtomicBool::new(false);
-static ITERATION_INDEX: AtomicU8 = AtomicU8::new(0);
-static CONTROL_CODE: AtomicU8 = AtomicU8::new(0);
-static mut CHANNEL: MaybeUninit<Channel<NoopRawMutex, u8, 1>> = MaybeUninit::uninit();
+const COMMAND_QUEUE_SIZE: usize = 4;
+/// how long to wait before showing the next page, after the display failed
+const DISPLAY_ERROR_DELAY: Duration = Duration::from_secs(1);
+/// how many pages shown before can be returned to via [`PageController::previous`]
+const HISTORY_SIZE: usize = 16;
+/// how often the page reached by the rotation itself is saved at most, see [`PageRotator::save_rotation_every`]
+const ROTATION_SAVE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// how many pages can be [pushed](PageController::push) onto each other
+const CALLER_STACK_SIZE: usize = 4;
 
-const CONTROL_CODE_NOTHING: u8 = 0;
-const CONTROL_CODE_NEXT: u8 = 1;
-const CONTROL_CODE_PREVIOUS: u8 = 2;
+static mut COMMANDS: Channel<NoopRawMutex, Command, COMMAND_QUEUE_SIZE> = Channel::new();
+static mut QUERIES: Mutex<NoopRawMutex, u32> = Mutex::new(0);
+static mut REPLIES: Signal<NoopRawMutex, (u32, Reply)> = Signal::new();
+static mut CAPTURE_BUFFER: Cell<Option<CaptureBuffer>> = Cell::new(None);
+
+/// the queue of commands sent by the [PageController] to the [PageRotator].
+fn commands() -> &'static Channel<NoopRawMutex, Command, COMMAND_QUEUE_SIZE> {
+    // the channel is only ever accessed via shared references from within one executor
+    unsafe { &*addr_of!(COMMANDS) }
+}
+
+/// the token of the last query of the [PageController], locked until the query is answered.
+fn queries() -> &'static Mutex<NoopRawMutex, u32> {
+    // the mutex is only ever accessed via shared references from within one executor
+    unsafe { &*addr_of!(QUERIES) }
+}
+
+/// the answers of the [PageRotator] to queries of the [PageController], tagged with the token of the query.
+fn replies() -> &'static Signal<NoopRawMutex, (u32, Reply)> {
+    // the signal is only ever accessed via shared references from within one executor
+    unsafe { &*addr_of!(REPLIES) }
+}
+
+/// the buffer of the capture waiting for the next frame, see [`PageController::capture`].
+fn capture_buffer() -> &'static Cell<Option<CaptureBuffer>> {
+    // the cell is only ever accessed via shared references from within one executor
+    unsafe { &*addr_of!(CAPTURE_BUFFER) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum Command {
+    Next,
+    Previous,
+    Pause,
+    Resume,
+    SetBrightness(u8),
+    SetOrientation(Orientation),
+    SetInverted(bool),
+    EnablePage(usize),
+    DisablePage(usize),
+    SetEnabledPages(u32),
+    JumpTo(usize),
+    Push(usize),
+    Pop,
+    SetPageDuration(usize, Duration),
+    QueryStatus(u32),
+    QueryPage(u32, usize),
+    QueryStats(u32, usize),
+    ResetStats,
+    Redraw,
+    Capture(u32),
+    Input(InputEvent),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Reply {
+    Status(RotatorStatus),
+    Page(Option<PageStatus>),
+    Stats(Option<FrameStats>),
+    Captured(Result<Size, CaptureError>),
+}
+
+/// the buffer handed over by [`PageController::capture`], to copy the next frame into
+#[derive(Clone, Copy)]
+struct CaptureBuffer {
+    token: u32,
+    data: *mut u8,
+    length: usize,
+}
+
+/// takes the [CaptureBuffer] back from the [PageRotator] when the capture is done or given up on
+struct LentBuffer;
+
+impl Drop for LentBuffer {
+    fn drop(&mut self) {
+        capture_buffer().set(None);
+    }
+}
+
+/// how the rotation continues after a page was shown
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum Navigation {
+    /// the duration of the page ran out, moving on to the next page
+    Rotate,
+    Next,
+    Previous,
+    JumpTo(usize),
+    /// show another page, returning to the current one on [`Navigation::Pop`]
+    Push(usize),
+    /// return to the page which pushed the current one
+    Pop,
+    /// show the current page again from the start, e.g. after the size of the display changed
+    Reactivate,
+}
+
+/// a snapshot of the state of the [PageRotator], as returned by [`PageController::status`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RotatorStatus {
+    /// the amount of registered pages
+    pub page_count: usize,
+    /// the index of the page currently shown
+    pub current_page: usize,
+    /// if the rotation is paused
+    pub paused: bool,
+    /// the brightness set via the [PageController], `None` if never changed
+    pub brightness: Option<u8>,
+    /// how many pages the current one was [pushed](PageController::push) onto, 0 if it is part of the rotation
+    pub depth: usize,
+    /// the orientation set via the [PageController]
+    pub orientation: Orientation,
+    /// if the colors of the display are inverted
+    pub inverted: bool,
+}
+
+/// the settings of a single page of the [PageRotator], as returned by [`PageController::page_status`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PageStatus {
+    /// how long the page is shown before rotating to the next one
+    pub duration: Duration,
+    /// if the page is part of the rotation, see [`PageController::disable_page`]
+    pub enabled: bool,
+}
 
 /// the main actor of `pagrs`.
 ///
@@ -30,94 +167,207 @@ const CONTROL_CODE_PREVIOUS: u8 = 2;
 /// page is active at any given time, rotating through them.
 ///
 /// **infos**:
-/// - it currently only works for with a [Ssd1306Async] as display.
+/// - the display is any [Backend], e.g. a buffered `Ssd1306Async` or a [MirrorBackend](crate::MirrorBackend).
 /// - it reserves the memory for the page vec ahead of time, statically, as to not need any `alloc`.
 /// - before it can rotate first the `init` method must be called
+/// - if created [`with_storage`](PageRotator::with_storage) the state of the rotation (current page, paused,
+///   brightness, disabled pages) is saved on each change via the [PageController] and restored by `init`. Moving on
+///   to the next page after its duration is saved at most once every 10 minutes, to spare the flash, see
+///   [`save_rotation_every`](PageRotator::save_rotation_every).
+/// - what happens during the rotation is published as [RotationEvent]s, see [`PageController::subscribe`].
+/// - the pages are shown in order of registration, unless another [RotationOrder] is set via
+///   [`set_order`](PageRotator::set_order). Going back returns to the pages in the order they were shown.
+/// - a page [pushed](PageController::push) onto another one is shown until it is popped, then the rotation continues
+///   with the page which pushed it.
 ///
 /// ## type parameters
 /// - `PAGE_COUNT`: the maximum amount of [pages](crate::Page) that can be registered to the rotator
-/// - `DI`, `SIZE`, `MODE`: type parameters that are passed 1:1 to the [Ssd1306Async] display
-pub struct PageRotator<'a, const PAGE_COUNT: usize, DI, SIZE, MODE> {
-    pages: Vec<PageWrapper<'a, DI, SIZE, MODE>, PAGE_COUNT>,
-    display: RefCell<Ssd1306Async<DI, SIZE, MODE>>,
+/// - `B`: the type of the [Backend] the pages are rendered onto
+pub struct PageRotator<'a, const PAGE_COUNT: usize, B> {
+    pages: Vec<PageWrapper<'a, B>, PAGE_COUNT>,
+    display: RefCell<B>,
+    state: Cell<RotationState>,
+    saved_state: Cell<Option<RotationState>>,
+    /// the token of the capture to send after the next frame
+    capture_requested: Cell<Option<u32>>,
+    pre_notification: Option<Duration>,
+    storage: Option<RefCell<&'a mut dyn StateStorage>>,
+    /// how often the page reached by the rotation itself is saved at most
+    rotation_save_interval: Duration,
+    /// when the state was saved last, or the rotation started
+    last_saved: Cell<Instant>,
+    order: RefCell<Option<&'a mut dyn RotationOrder>>,
+    history: RefCell<Deque<usize, HISTORY_SIZE>>,
+    /// the next page already announced via [`RotationEvent::NextPageComing`]
+    planned_next: Cell<Option<usize>>,
+    /// the pages which [pushed](PageController::push) the current one, the latest last
+    callers: RefCell<Vec<usize, CALLER_STACK_SIZE>>,
+    orientation: Cell<Orientation>,
+    inverted: Cell<bool>,
 }
 
-impl<'a, const PAGE_COUNT: usize, DI, SIZE, MODE> PageRotator<'a, PAGE_COUNT, DI, SIZE, MODE> {
+impl<'a, const PAGE_COUNT: usize, B> PageRotator<'a, PAGE_COUNT, B> {
     /// create a new [PageRotator] and reserves the memory for the page vector.
-    pub fn new(display: Ssd1306Async<DI, SIZE, MODE>) -> Self {
+    pub fn new(display: B) -> Self {
         Self {
             pages: Vec::new(),
             display: RefCell::new(display),
+            state: Cell::new(RotationState::default()),
+            saved_state: Cell::new(None),
+            capture_requested: Cell::new(None),
+            pre_notification: None,
+            storage: None,
+            rotation_save_interval: ROTATION_SAVE_INTERVAL,
+            last_saved: Cell::new(Instant::from_ticks(0)),
+            order: RefCell::new(None),
+            history: RefCell::new(Deque::new()),
+            planned_next: Cell::new(None),
+            callers: RefCell::new(Vec::new()),
+            orientation: Cell::new(Orientation::Rotate0),
+            inverted: Cell::new(false),
+        }
+    }
+
+    /// create a new [PageRotator] which persists its state in the `storage`, to restore it on the next `init`.
+    pub fn with_storage(display: B, storage: &'a mut dyn StateStorage) -> Self {
+        Self {
+            storage: Some(RefCell::new(storage)),
+            ..Self::new(display)
         }
     }
+
+    /// publish a [`RotationEvent::NextPageComing`] the given time before the rotation moves on to the next page,
+    /// e.g. to prefetch the data of the next page
+    pub fn notify_before_rotation(&mut self, lead: Duration) {
+        self.pre_notification = Some(lead);
+    }
+
+    /// save the page reached by the rotation itself at most once per `interval`, instead of every 10 minutes.
+    /// Saving every page moved on to would wear out a flash quickly, with pages shown for seconds only. After a restart
+    /// the rotation continues with the page saved last, so a shorter interval loses less of the rotation.
+    /// Changes via the [PageController] are saved right away regardless.
+    pub fn save_rotation_every(&mut self, interval: Duration) {
+        self.rotation_save_interval = interval;
+    }
+
+    /// decide which page is shown next by the given [RotationOrder] instead of the order of registration,
+    /// e.g. a [Weighted](crate::Weighted) or [Shuffle](crate::Shuffle) order
+    pub fn set_order(&mut self, order: &'a mut dyn RotationOrder) {
+        *self.order.get_mut() = Some(order);
+    }
 }
 
-impl<'a, const PAGE_COUNT: usize, DI, SIZE> PageRotator<'a, PAGE_COUNT, DI, SIZE, BufferedGraphicsModeAsync<SIZE>>
-where
-    DI: AsyncWriteOnlyDataCommand,
-    SIZE: DisplaySizeAsync,
-{
-    /// initialize the display
+impl<'a, const PAGE_COUNT: usize, B: Backend> PageRotator<'a, PAGE_COUNT, B> {
+    /// initialize the display, restoring the persisted state if a storage is configured
     pub async fn init(&mut self) -> Result<(), DisplayError> {
-        let mut display = self.display.borrow_mut();
+        self.restore_state();
+
+        let brightness = self.state.get().brightness;
+        let display = self.display.get_mut();
         display.init().await?;
-        SplashScreen::new(&mut display).show().await;
+        if let Some(brightness) = brightness {
+            display.set_brightness(brightness).await?;
+        }
+        SplashScreen::new(display).show().await;
 
         Ok(())
     }
 
     /// start the page rotation, cycling through all registered [pages](crate::Page).
     /// This method never returns. All pages must be registered before calling this method.
-    pub async fn rotate(&self, spawner: Spawner) -> ! {
-        let mut index = 0;
-        let page_count = self.pages.len();
-
-        let channel = unsafe { CHANNEL.write(Channel::new()) };
-
-        loop {
-            let iter_index = (ITERATION_INDEX.load(Ordering::Acquire) + 1) % 255;
-            ITERATION_INDEX.store(iter_index, Ordering::Release);
+    ///
+    /// **info**: the `spawner` isn't used anymore, see [`run`](PageRotator::run).
+    #[deprecated(note = "the spawner isn't needed anymore, use `run` instead")]
+    pub async fn rotate(&self, _spawner: Spawner) -> ! {
+        self.run().await
+    }
 
-            CANCEL_PAGE.store(false, Ordering::Release);
+    /// start the page rotation, cycling through all registered [pages](crate::Page).
+    /// This method never returns. All pages must be registered before calling this method.
+    ///
+    /// If the display fails while showing a page, a [`RotationEvent::DisplayError`] is published and the rotation
+    /// continues with the next page shortly after.
+    ///
+    /// **info**: unlike [`rotate`](PageRotator::rotate) no task is spawned to end each page after its duration: the
+    /// rotator waits for the end of the page, the next frame and the commands of the [PageController] at once. So it
+    /// runs on any executor, e.g. in the tests on the host.
+    // the display is only borrowed here once `init` is done, which takes `&mut self`. Calling `run` twice at the
+    // same time is a bug and panics instead of sharing the display.
+    #[allow(clippy::await_holding_refcell_ref)]
+    pub async fn run(&self) -> ! {
+        let mut display = self.display.borrow_mut();
 
-            let control_code = CONTROL_CODE.load(Ordering::Acquire);
-            CONTROL_CODE.store(CONTROL_CODE_NOTHING, Ordering::Release);
-            match control_code {
-                CONTROL_CODE_PREVIOUS => {
-                    index = (index + page_count - 2) % page_count;
-                },
-                _ => {},
-            }
+        let mut state = self.state.get();
+        if state.index >= self.pages.len() {
+            state.index = 0;
+            self.state.set(state);
+        }
+        info!("rotating {} pages, starting with page {}", self.pages.len(), self.state.get().index);
+        self.last_saved.set(Instant::now());
 
+        loop {
+            let index = self.state.get().index;
             let page = self.pages.get(index).unwrap();
-            index = (index + 1) % page_count;
 
-            let duration = page.custom_duration.unwrap_or(Duration::from_secs(5));
-            spawner.must_spawn(next_pane_counter(duration, channel.receiver(), iter_index));
+            let navigation = match self.show(index, page, &mut display).await {
+                Ok(navigation) => navigation,
+                Err(error) => {
+                    error!("showing page {} failed: {:?}", index, error);
+                    publish(RotationEvent::DisplayError { index, error });
+                    Timer::after(DISPLAY_ERROR_DELAY).await;
+                    Navigation::Rotate
+                }
+            };
 
-            page
-                .take_over(&mut self.display.borrow_mut(), || {
-                    CANCEL_PAGE.load(Ordering::Acquire)
-                })
-                .await.unwrap();
+            let mut state = self.state.get();
+            // leaving a pushed page any other way than popping it returns to the rotation
+            if !matches!(navigation, Navigation::Push(_) | Navigation::Pop | Navigation::Reactivate) {
+                self.callers.borrow_mut().clear();
+            }
+            state.index = match navigation {
+                Navigation::Rotate | Navigation::Next => self.next_page(index),
+                Navigation::Previous => self.previous_page(index),
+                Navigation::JumpTo(target) => target,
+                Navigation::Push(target) => self.push(index, target),
+                Navigation::Pop => self.pop(index),
+                Navigation::Reactivate => index,
+            };
+            self.planned_next.set(None);
+            if matches!(navigation, Navigation::Rotate | Navigation::Next | Navigation::JumpTo(_)) && state.index != index {
+                self.remember(index);
+            }
+            trace!("leaving page {} via {:?}, showing page {} next", index, navigation, state.index);
+            self.state.set(state);
+            // the pages chosen via the controller are persisted right away, saving each rotation would wear out a flash
+            if !matches!(navigation, Navigation::Rotate) || self.last_saved.get().elapsed() >= self.rotation_save_interval {
+                self.save_state();
+            }
         }
     }
 
     /// register a new [Page] with the default duration for the page rotation.
-    pub fn add_page<P: Page<Ssd1306Async<DI, SIZE, BufferedGraphicsModeAsync<SIZE>>>>(&mut self, page: &'a mut P) -> Result<(), ()> {
-        if let Err(_) = self.pages.push(PageWrapper::new(page)) {
-            return Err(());
+    /// Returns the page back if already `PAGE_COUNT` pages are registered.
+    pub fn add_page<P: Page<B>>(&mut self, page: &'a mut P) -> Result<(), &'a mut P> {
+        if self.pages.is_full() {
+            return Err(page);
         }
 
+        // can't fail, there is room left
+        let _ = self.pages.push(PageWrapper::new(page));
+
         Ok(())
     }
 
-    /// register a new [Page] with a custom duration for the page rotation
-    pub fn add_page_with_duration<P: Page<Ssd1306Async<DI, SIZE, BufferedGraphicsModeAsync<SIZE>>>>(&mut self, page: &'a mut P, duration: Duration) -> Result<(), ()> {
-        if let Err(_) = self.pages.push(PageWrapper::with_custom_duration(page, duration)) {
-            return Err(());
+    /// register a new [Page] with a custom duration for the page rotation.
+    /// Returns the page back if already `PAGE_COUNT` pages are registered.
+    pub fn add_page_with_duration<P: Page<B>>(&mut self, page: &'a mut P, duration: Duration) -> Result<(), &'a mut P> {
+        if self.pages.is_full() {
+            return Err(page);
         }
 
+        // can't fail, there is room left
+        let _ = self.pages.push(PageWrapper::with_custom_duration(page, duration));
+
         Ok(())
     }
 
@@ -125,18 +375,430 @@ where
     pub fn controller(&self) -> PageController {
         PageController {}
     }
-}
 
-#[embassy_executor::task(pool_size = 2)]
-async fn next_pane_counter(time: Duration, rec: Receiver<'static, NoopRawMutex, u8, 1>, my_index: u8) {
-    select(Timer::after(time), rec.receive()).await;
+    /// show the page until its duration is over or the [PageController] navigates away
+    // the page is only borrowed while it is shown, the pushed pages and the statistics don't borrow it
+    #[allow(clippy::await_holding_refcell_ref)]
+    async fn show(&self, index: usize, page: &PageWrapper<'a, B>, display: &mut B) -> Result<Navigation, DisplayError> {
+        let mut interval = page.frame_interval();
+        let mut shown_since = Instant::now();
+        let mut deadline = shown_since + page.duration();
+        let mut notify_at = self.notification_time(deadline);
+        let mut page_ref = page.page();
+        debug!("showing page {} for {} ms, {:?}", index, page.duration().as_millis(), page_ref.refresh());
+        let clear_each_frame = page_ref.clear_each_frame();
+        let mut scroll = page_ref.scroll();
+        let mut software_scroll = false;
+        let mut hardware_scroll = false;
+        let mut held = false;
+
+        page_ref.activated()?;
+        publish(RotationEvent::PageActivated { index });
+        // the page gets deactivated even if showing it failed
+        let shown = async {
+            display.clear_buffer();
+            let activated_at = Instant::now();
+            let mut frame_started = activated_at;
+            let mut next_frame = frame_started;
+            let navigation = loop {
+                let started = Instant::now();
+                // the memory of the display must not be written while it scrolls
+                if hardware_scroll {
+                    display.stop_scroll().await?;
+                    hardware_scroll = false;
+                }
+                page_ref.render(display)?;
+                if let Some(region) = scroll.filter(|_| software_scroll) {
+                    let steps = (started - activated_at).as_ticks() / region.speed.step_interval().as_ticks().max(1);
+                    if !scroll_in_software(display, &region, steps)? {
+                        warn!("page {} can't scroll, the display can neither scroll in hardware nor read back its frame (use a ScrollingSsd1306)", index);
+                        scroll = None;
+                    }
+                }
+                let rendered = Instant::now();
+                display.flush().await?;
+                let flushed = Instant::now();
+                page.update_stats(|stats| {
+                    stats.record(rendered - started, flushed - rendered, interval);
+                    stats.shown += started - frame_started;
+                });
+                frame_started = started;
+                if let Some(region) = scroll.filter(|_| !software_scroll) {
+                    hardware_scroll = display.start_scroll(&region).await?;
+                    if hardware_scroll {
+                        // the display scrolls on its own, a new frame would restart it
+                        interval = None;
+                    } else {
+                        trace!("page {} scrolls in software", index);
+                        software_scroll = true;
+                        let step = region.speed.step_interval();
+                        interval = Some(interval.map_or(step, |interval| interval.min(step)));
+                    }
+                }
+                next_frame = match interval {
+                    // frames rendered in between, e.g. after a command, don't shift the schedule
+                    Some(interval) if next_frame <= started => next_frame + interval,
+                    Some(_) => next_frame,
+                    None => Instant::MAX,
+                };
+                // skip the missed frames instead of catching up with them, which would leave no time for commands or other tasks
+                if let Some(interval) = interval.filter(|_| next_frame < flushed) {
+                    trace!("page {} took {} us for a frame, skipping the missed frames", index, (flushed - started).as_micros());
+                    next_frame = flushed + interval;
+                }
+                if let Some(token) = self.capture_requested.take() {
+                    self.capture(token, display);
+                }
+
+                // the duration changes when resumed, set via the controller or required by the page
+                let duration = page_ref.required_duration().map_or(page.duration(), |required| required.max(page.duration()));
+                if shown_since + duration != deadline {
+                    held = false;
+                    deadline = shown_since + duration;
+                    notify_at = self.notification_time(deadline);
+                }
+
+                // a page holding the rotation rotates out as soon as it is done
+                if held && !page_ref.hold() {
+                    debug!("page {} released the rotation", index);
+                    break Navigation::Rotate;
+                }
+
+                // a pushed page stays until it is popped
+                let rotating = !held && !self.state.get().paused && self.callers.borrow().is_empty();
+                let timeout = if rotating { notify_at.unwrap_or(deadline) } else { Instant::MAX };
+                match select3(Timer::at(next_frame), Timer::at(timeout), commands().receive()).await {
+                    Either3::First(_) => {}
+                    Either3::Second(_) => match notify_at.take() {
+                        Some(_) => publish(RotationEvent::NextPageComing {
+                            index: self.plan_next_page(index),
+                            after: deadline.saturating_duration_since(Instant::now()),
+                        }),
+                        None if page_ref.hold() => {
+                            debug!("page {} holds the rotation", index);
+                            held = true;
+                        }
+                        None => break Navigation::Rotate,
+                    },
+                    Either3::Third(command) => {
+                        // input is offered to the page first, only what it doesn't consume is used for navigation
+                        let command = match command {
+                            Command::Input(event) if page_ref.input(event) => {
+                                trace!("input {:?} consumed by page {}", event, index);
+                                None
+                            }
+                            Command::Input(InputEvent::Up) => Some(Command::Previous),
+                            Command::Input(InputEvent::Down) => Some(Command::Next),
+                            Command::Input(InputEvent::Rotate(steps)) if steps < 0 => Some(Command::Previous),
+                            Command::Input(InputEvent::Rotate(steps)) if steps > 0 => Some(Command::Next),
+                            Command::Input(InputEvent::Select) => page_ref.drill_down().map(Command::Push),
+                            Command::Input(InputEvent::Back) => Some(Command::Pop),
+                            Command::Input(_) => None,
+                            command => Some(command),
+                        };
 
-    let current_index = ITERATION_INDEX.load(Ordering::Acquire);
-    if my_index != current_index {
-        return
+                        let was_paused = self.state.get().paused;
+                        let navigation = match command {
+                            Some(command) => self.apply(command, display).await?,
+                            None => None,
+                        };
+                        if let Some(navigation) = navigation {
+                            break navigation;
+                        }
+
+                        // a resumed page gets its full duration again
+                        if was_paused && !self.state.get().paused {
+                            shown_since = Instant::now();
+                        }
+                    }
+                }
+
+                if clear_each_frame {
+                    display.clear_buffer();
+                }
+            };
+
+            Ok::<Navigation, DisplayError>(navigation)
+        }
+        .await;
+        // a hardware scroll never outlasts its page
+        let stopped = if hardware_scroll { display.stop_scroll().await } else { Ok(()) };
+        let deactivated = page_ref.deactivated();
+        publish(RotationEvent::PageDeactivated { index });
+        let stats = page.stats();
+        debug!(
+            "page {}: {} fps, {} missed ticks, render avg {} us max {} us, flush avg {} us max {} us",
+            index,
+            stats.frames_per_second(),
+            stats.missed_ticks,
+            stats.average_render().as_micros(),
+            stats.render_max.as_micros(),
+            stats.average_flush().as_micros(),
+            stats.flush_max.as_micros(),
+        );
+
+        let navigation = shown?;
+        stopped?;
+        deactivated?;
+        Ok(navigation)
     }
 
-    CANCEL_PAGE.store(true, Ordering::Release);
+    /// when to publish the [`RotationEvent::NextPageComing`] for a page shown until `deadline`
+    fn notification_time(&self, deadline: Instant) -> Option<Instant> {
+        self.pre_notification
+            .map(|lead| deadline.checked_sub(lead).unwrap_or(Instant::MIN))
+    }
+
+    /// apply a command of the [PageController], returning if the current page shall be left
+    async fn apply(&self, command: Command, display: &mut B) -> Result<Option<Navigation>, DisplayError> {
+        debug!("command {:?}", command);
+        let mut state = self.state.get();
+        let mut navigation = None;
+
+        match command {
+            Command::Next => navigation = Some(Navigation::Next),
+            Command::Previous => navigation = Some(Navigation::Previous),
+            Command::Pause => {
+                if !state.paused {
+                    publish(RotationEvent::Paused);
+                }
+                state.paused = true;
+            }
+            Command::Resume => {
+                if state.paused {
+                    publish(RotationEvent::Resumed);
+                }
+                state.paused = false;
+            }
+            Command::SetBrightness(brightness) => {
+                display.set_brightness(brightness).await?;
+                state.brightness = Some(brightness);
+            }
+            Command::SetOrientation(orientation) => {
+                display.set_orientation(orientation).await?;
+                // the page lays out its content again for the new size of the display
+                if self.orientation.replace(orientation) != orientation {
+                    navigation = Some(Navigation::Reactivate);
+                }
+            }
+            Command::SetInverted(inverted) => {
+                display.set_inverted(inverted).await?;
+                self.inverted.set(inverted);
+            }
+            Command::EnablePage(index) => state.set_disabled(index, false),
+            Command::DisablePage(index) => {
+                state.set_disabled(index, true);
+                if index == state.index {
+                    navigation = Some(Navigation::Next);
+                }
+            }
+            Command::SetEnabledPages(enabled) => {
+                state.disabled_pages = !enabled;
+                if state.is_disabled(state.index) {
+                    navigation = Some(Navigation::Next);
+                }
+            }
+            Command::JumpTo(index) => {
+                if index < self.pages.len() {
+                    navigation = Some(Navigation::JumpTo(index));
+                }
+            }
+            Command::Push(index) => {
+                if index < self.pages.len() && index != state.index {
+                    navigation = Some(Navigation::Push(index));
+                }
+            }
+            Command::Pop => {
+                if !self.callers.borrow().is_empty() {
+                    navigation = Some(Navigation::Pop);
+                }
+            }
+            Command::SetPageDuration(index, duration) => {
+                if let Some(page) = self.pages.get(index) {
+                    page.set_duration(duration);
+                }
+            }
+            Command::QueryStatus(token) => replies().signal((token, Reply::Status(self.status()))),
+            Command::QueryPage(token, index) => replies().signal((token, Reply::Page(self.page_status(index)))),
+            Command::QueryStats(token, index) => {
+                replies().signal((token, Reply::Stats(self.pages.get(index).map(|page| page.stats()))))
+            }
+            Command::ResetStats => self.pages.iter().for_each(|page| page.update_stats(|stats| *stats = FrameStats::default())),
+            Command::Capture(token) => self.capture_requested.set(Some(token)),
+            // every command renders a new frame right away
+            Command::Redraw => {}
+            // handled by the page being shown
+            Command::Input(_) => {}
+        }
+
+        self.state.set(state);
+        // queries and redraws don't change the state, even if the page shown changed since the last save
+        if matches!(
+            command,
+            Command::Pause
+                | Command::Resume
+                | Command::SetBrightness(_)
+                | Command::EnablePage(_)
+                | Command::DisablePage(_)
+                | Command::SetEnabledPages(_)
+        ) {
+            self.save_state();
+        }
+
+        Ok(navigation)
+    }
+
+    /// copy the frame just flushed into the buffer of the [PageController] waiting for it. A capture given up on
+    /// took its buffer back already and is skipped, leaving the buffer of the next capture to its own command.
+    fn capture(&self, token: u32, display: &B) {
+        let Some(buffer) = capture_buffer().get().filter(|buffer| buffer.token == token) else {
+            return;
+        };
+        capture_buffer().set(None);
+
+        let size = display.bounding_box().size;
+        let length = capture_size(size);
+        let captured = if display.read_pixel(Point::zero()).is_none() {
+            Err(CaptureError::Unsupported)
+        } else if buffer.length < length {
+            Err(CaptureError::BufferTooSmall)
+        } else {
+            // the buffer is still borrowed by the waiting `capture`, which takes it back before returning or when it
+            // is dropped. Nothing else runs on the executor while the frame is copied.
+            let data = unsafe { core::slice::from_raw_parts_mut(buffer.data, length) };
+            for (index, byte) in data.iter_mut().enumerate() {
+                *byte = pack_byte(index, size, |point| display.read_pixel(point));
+            }
+            Ok(size)
+        };
+
+        replies().signal((token, Reply::Captured(captured)));
+    }
+
+    fn status(&self) -> RotatorStatus {
+        let state = self.state.get();
+        RotatorStatus {
+            page_count: self.pages.len(),
+            current_page: state.index,
+            paused: state.paused,
+            brightness: state.brightness,
+            depth: self.callers.borrow().len(),
+            orientation: self.orientation.get(),
+            inverted: self.inverted.get(),
+        }
+    }
+
+    fn page_status(&self, index: usize) -> Option<PageStatus> {
+        self.pages.get(index).map(|page| PageStatus {
+            duration: page.duration(),
+            enabled: !self.state.get().is_disabled(index),
+        })
+    }
+
+    fn candidates(&self) -> Candidates {
+        Candidates::new(self.pages.len(), self.state.get().disabled_pages)
+    }
+
+    /// the page the [RotationOrder] picks after the given one, without moving on to it yet
+    fn plan_next_page(&self, index: usize) -> usize {
+        let candidates = self.candidates();
+        let next = match self.order.borrow_mut().as_mut() {
+            Some(order) => order.next(index, &candidates),
+            None => Sequential.next(index, &candidates),
+        };
+        let next = next.filter(|next| candidates.is_enabled(*next)).unwrap_or(index);
+
+        self.planned_next.set(Some(next));
+        next
+    }
+
+    /// the page to show after the given one, the one announced before if it is still enabled.
+    /// If all pages are disabled the current page stays.
+    fn next_page(&self, index: usize) -> usize {
+        match self.planned_next.take() {
+            Some(planned) if self.candidates().is_enabled(planned) => planned,
+            _ => self.plan_next_page(index),
+        }
+    }
+
+    /// the page shown before the given one which is still enabled. Without any history left the page
+    /// before it in order of registration is shown.
+    fn previous_page(&self, index: usize) -> usize {
+        let candidates = self.candidates();
+        let mut history = self.history.borrow_mut();
+        while let Some(previous) = history.pop_back() {
+            if previous != index && candidates.is_enabled(previous) {
+                return previous;
+            }
+        }
+
+        candidates.before(index).unwrap_or(index)
+    }
+
+    /// show the `target` page on top of the current one. If too many pages are pushed already, the oldest caller
+    /// is forgotten.
+    fn push(&self, index: usize, target: usize) -> usize {
+        let mut callers = self.callers.borrow_mut();
+        if callers.is_full() {
+            callers.remove(0);
+        }
+        let _ = callers.push(index);
+        publish(RotationEvent::PagePushed { index: target, onto: index });
+
+        target
+    }
+
+    /// the page which pushed the current one
+    fn pop(&self, index: usize) -> usize {
+        self.callers.borrow_mut().pop().unwrap_or(index)
+    }
+
+    /// remember the page being left, to return to it via [`PageController::previous`]
+    fn remember(&self, index: usize) {
+        let mut history = self.history.borrow_mut();
+        if history.is_full() {
+            history.pop_front();
+        }
+        let _ = history.push_back(index);
+    }
+
+    fn restore_state(&mut self) {
+        let Some(storage) = self.storage.as_mut() else {
+            return;
+        };
+
+        let mut buffer = [0; RotationState::ENCODED_SIZE];
+        let restored = match storage.get_mut().load(&mut buffer) {
+            Ok(length) => RotationState::decode(&buffer[..length]),
+            Err(_) => None,
+        };
+
+        if let Some(state) = restored {
+            debug!("restored the rotation state: page {}, paused {}", state.index, state.paused);
+            self.state.set(state);
+            self.saved_state.set(Some(state));
+        }
+    }
+
+    fn save_state(&self) {
+        let Some(storage) = self.storage.as_ref() else {
+            return;
+        };
+
+        let state = self.state.get();
+        if self.saved_state.get() == Some(state) {
+            return;
+        }
+
+        // a failing storage must not stop the rotation, it is retried on the next change
+        match storage.borrow_mut().save(&state.encode()) {
+            Ok(()) => {
+                self.saved_state.set(Some(state));
+                self.last_saved.set(Instant::now());
+            }
+            Err(error) => warn!("saving the rotation state failed: {:?}", error),
+        }
+    }
 }
 
 /// control the [PageRotator] after it starts rotating by sending commands to it via this
@@ -150,15 +812,195 @@ impl PageController {
 
     /// instruct the [PageRotator] to cycle now to the next page
     pub async fn next(&self) {
-        CONTROL_CODE.store(CONTROL_CODE_NEXT, Ordering::Release);
-        let channel = unsafe { CHANNEL.assume_init_ref() };
-        channel.send(0).await;
+        commands().send(Command::Next).await;
     }
 
-    /// instruct the [PageRotator] to cycle now to the previous page
+    /// instruct the [PageRotator] to return now to the page shown before the current one
     pub async fn previous(&self) {
-        CONTROL_CODE.store(CONTROL_CODE_PREVIOUS, Ordering::Release);
-        let channel = unsafe { CHANNEL.assume_init_ref() };
-        channel.send(0).await;
+        commands().send(Command::Previous).await;
+    }
+
+    /// instruct the [PageRotator] to show the page with the given index on top of the current one, e.g. the details of an
+    /// overview page. The pushed page is shown until [`pop`](PageController::pop) is called, even if it is disabled,
+    /// then the current page is shown again. Navigating to another page any other way leaves all pushed pages.
+    pub async fn push(&self, index: usize) {
+        commands().send(Command::Push(index)).await;
+    }
+
+    /// instruct the [PageRotator] to return from a [pushed](PageController::push) page to the page which pushed it.
+    /// Does nothing if no page was pushed.
+    pub async fn pop(&self) {
+        commands().send(Command::Pop).await;
+    }
+
+    /// instruct the [PageRotator] to keep showing the current page until [`resume`](PageController::resume) is called.
+    /// Navigating to the next or previous page is still possible.
+    pub async fn pause(&self) {
+        commands().send(Command::Pause).await;
+    }
+
+    /// instruct the [PageRotator] to continue rotating. The current page is shown for its full duration again.
+    pub async fn resume(&self) {
+        commands().send(Command::Resume).await;
+    }
+
+    /// instruct the [PageRotator] to change the brightness (contrast) of the display
+    pub async fn set_brightness(&self, brightness: u8) {
+        commands().send(Command::SetBrightness(brightness)).await;
+    }
+
+    /// instruct the [PageRotator] to rotate the content of the display, e.g. when the device is held upside down.
+    /// The current page is shown again from the start, seeing the new size of the display for a portrait [Orientation].
+    /// The orientation replaces the `DisplayRotation` a `Ssd1306Async` was created with.
+    pub async fn set_orientation(&self, orientation: Orientation) {
+        commands().send(Command::SetOrientation(orientation)).await;
+    }
+
+    /// instruct the [PageRotator] to invert the colors of the display, showing lit pixels dark and vice versa
+    pub async fn set_inverted(&self, inverted: bool) {
+        commands().send(Command::SetInverted(inverted)).await;
+    }
+
+    /// instruct the [PageRotator] to show the page with the given index again, after it was disabled
+    pub async fn enable_page(&self, index: usize) {
+        commands().send(Command::EnablePage(index)).await;
+    }
+
+    /// instruct the [PageRotator] to skip the page with the given index (in order of registration) during rotation.
+    /// Only the first 32 pages can be disabled.
+    pub async fn disable_page(&self, index: usize) {
+        commands().send(Command::DisablePage(index)).await;
+    }
+
+    /// instruct the [PageRotator] to show only the pages whose bit is set in `enabled`, e.g. `0b101` for the first and
+    /// the third page, and to skip all others among the first 32 pages. Unlike a series of
+    /// [`enable_page`](PageController::enable_page) and [`disable_page`](PageController::disable_page) the pages are
+    /// switched at once, saving the state only once.
+    pub async fn set_enabled_pages(&self, enabled: u32) {
+        commands().send(Command::SetEnabledPages(enabled)).await;
+    }
+
+    /// instruct the [PageRotator] to show the page with the given index (in order of registration) now.
+    /// An index without a page is ignored.
+    pub async fn jump_to(&self, index: usize) {
+        commands().send(Command::JumpTo(index)).await;
+    }
+
+    /// instruct the [PageRotator] to show the page with the given index for `duration` from now on.
+    /// The change is not persisted in the [StateStorage](crate::StateStorage).
+    pub async fn set_page_duration(&self, index: usize, duration: Duration) {
+        commands().send(Command::SetPageDuration(index, duration)).await;
+    }
+
+    /// copy the next frame shown by the [PageRotator] into `buffer`, which must hold at least
+    /// [`capture_size`](crate::capture_size) bytes of the display size. See [CapturedFrame] for the layout and encodings.
+    ///
+    /// **info**: only [backends](crate::Backend) that can read back their frame support capturing, e.g. the
+    /// [ScrollingSsd1306](crate::ScrollingSsd1306) of a single display or the [MirrorBackend](crate::MirrorBackend).
+    /// A plain `Ssd1306Async` keeps its buffer private and answers [`Unsupported`](CaptureError::Unsupported).
+    /// Waits until the [PageRotator] is rotating and the queries of other tasks are answered. The [PageRotator] copies
+    /// the frame right into the `buffer`, it never waits for the capture.
+    pub async fn capture<'b>(&self, buffer: &'b mut [u8]) -> Result<CapturedFrame<'b>, CaptureError> {
+        let token = next_query().await;
+        capture_buffer().set(Some(CaptureBuffer {
+            token: *token,
+            data: buffer.as_mut_ptr(),
+            length: buffer.len(),
+        }));
+        let lent = LentBuffer;
+        let Reply::Captured(captured) = query(*token, Command::Capture(*token)).await else {
+            unreachable!("a capture is answered with the size of the frame")
+        };
+        drop(lent);
+
+        CapturedFrame::new(captured?, buffer).ok_or(CaptureError::BufferTooSmall)
+    }
+
+    /// instruct the [PageRotator] to render a new frame of the page currently shown now,
+    /// e.g. for a page with [`Refresh::OnDemand`](crate::Refresh::OnDemand) after its data changed
+    pub async fn redraw(&self) {
+        commands().send(Command::Redraw).await;
+    }
+
+    /// send an [InputEvent] to the page currently shown. If the page doesn't consume it, it is used for navigation,
+    /// see [InputEvent].
+    pub async fn input(&self, event: InputEvent) {
+        commands().send(Command::Input(event)).await;
+    }
+
+    /// ask the [PageRotator] for the timing statistics of the frames shown of the page with the given index,
+    /// `None` if there is no such page. The statistics cover all times the page was shown since the start or the last
+    /// [`reset_frame_stats`](PageController::reset_frame_stats).
+    ///
+    /// **info**: waits until the [PageRotator] is rotating and the queries of other tasks are answered.
+    pub async fn frame_stats(&self, index: usize) -> Option<FrameStats> {
+        let token = next_query().await;
+        let Reply::Stats(stats) = query(*token, Command::QueryStats(*token, index)).await else {
+            unreachable!("a statistics query is answered with the statistics")
+        };
+
+        stats
+    }
+
+    /// instruct the [PageRotator] to start collecting the timing statistics of all pages anew,
+    /// e.g. after changing the bus speed
+    pub async fn reset_frame_stats(&self) {
+        commands().send(Command::ResetStats).await;
+    }
+
+    /// receive the [events](RotationEvent) of the [PageRotator], e.g. to log which pages were shown.
+    /// `None` if already 4 subscribers exist.
+    pub fn subscribe(&self) -> Option<RotationEvents> {
+        subscribe()
+    }
+
+    /// ask the [PageRotator] for its current state.
+    ///
+    /// **info**: waits until the [PageRotator] is rotating and the queries of other tasks are answered.
+    pub async fn status(&self) -> RotatorStatus {
+        let token = next_query().await;
+        let Reply::Status(status) = query(*token, Command::QueryStatus(*token)).await else {
+            unreachable!("a status query is answered with the status")
+        };
+
+        status
+    }
+
+    /// ask the [PageRotator] for the settings of the page with the given index, `None` if there is no such page.
+    ///
+    /// **info**: waits until the [PageRotator] is rotating and the queries of other tasks are answered.
+    pub async fn page_status(&self, index: usize) -> Option<PageStatus> {
+        let token = next_query().await;
+        let Reply::Page(page) = query(*token, Command::QueryPage(*token, index)).await else {
+            unreachable!("a page query is answered with the page")
+        };
+
+        page
+    }
+}
+
+/// wait until the queries of other tasks are answered, returning the token of the next query.
+/// No other query starts until the returned guard is dropped.
+async fn next_query() -> MutexGuard<'static, NoopRawMutex, u32> {
+    let mut token = queries().lock().await;
+    *token = token.wrapping_add(1);
+    token
+}
+
+/// send the query to the [PageRotator] and wait for its reply. Replies to queries given up on before, e.g. by a
+/// timeout, carry another token and are skipped.
+async fn query(token: u32, command: Command) -> Reply {
+    commands().send(command).await;
+    loop {
+        let (answered, reply) = replies().wait().await;
+        if answered == token {
+            return reply;
+        }
+    }
+}
+
+impl Default for PageController {
+    fn default() -> Self {
+        Self::new()
     }
 }
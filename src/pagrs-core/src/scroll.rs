@@ -0,0 +1,159 @@
+use core::iter::once;
+use display_interface::DisplayError;
+use embassy_time::Duration;
+use embedded_graphics_core::geometry::Point;
+use embedded_graphics_core::Pixel;
+use crate::backend::Backend;
+
+/// the time of a single frame of the SSD1306 with the oscillator settings used by its `init`, about 107 Hz
+const DISPLAY_FRAME: Duration = Duration::from_micros(9_350);
+
+/// a region of the display scrolling continuously while a [Page](crate::Page) is shown, as returned by
+/// [`Page::scroll`](crate::Page::scroll), e.g. a news ticker.
+///
+/// The region spans the whole width of the display, from the `start_page` to the `end_page` (inclusive), counted in
+/// pages of 8 pixel rows as the SSD1306 does.
+///
+/// ## example
+/// the lowest 16 pixels of a 128x64 display move to the left, one pixel every 4 frames of the display:
+/// ```rust
+/// use pagrs_core::{Scroll, ScrollDirection, ScrollSpeed};
+///
+/// let ticker = Scroll::new(6, 7, ScrollDirection::Left, ScrollSpeed::Frames4);
+/// assert_eq!(ticker.rows(64), 48..64);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Scroll {
+    pub start_page: u8,
+    pub end_page: u8,
+    pub direction: ScrollDirection,
+    pub speed: ScrollSpeed,
+}
+
+/// in which direction the content of a [Scroll] region moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScrollDirection {
+    Left,
+    Right,
+    /// to the left and up by the given amount of pixel rows per step. The vertical movement applies to the whole
+    /// height of the display.
+    DiagonalLeft(u8),
+    /// to the right and up by the given amount of pixel rows per step. The vertical movement applies to the whole
+    /// height of the display.
+    DiagonalRight(u8),
+}
+
+/// how many frames of the display pass between two steps of a [Scroll], each step moving the content by one pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScrollSpeed {
+    Frames2,
+    Frames3,
+    Frames4,
+    Frames5,
+    Frames25,
+    Frames64,
+    Frames128,
+    Frames256,
+}
+
+impl Scroll {
+    pub const fn new(start_page: u8, end_page: u8, direction: ScrollDirection, speed: ScrollSpeed) -> Self {
+        Self {
+            start_page,
+            end_page,
+            direction,
+            speed,
+        }
+    }
+
+    /// the pixel rows of the region, on a display of the given height
+    pub fn rows(&self, height: u32) -> core::ops::Range<u32> {
+        let start = (self.start_page as u32 * 8).min(height);
+        let end = ((self.end_page as u32 + 1) * 8).clamp(start, height);
+        start..end
+    }
+}
+
+impl ScrollSpeed {
+    pub const fn frames(&self) -> u32 {
+        match self {
+            ScrollSpeed::Frames2 => 2,
+            ScrollSpeed::Frames3 => 3,
+            ScrollSpeed::Frames4 => 4,
+            ScrollSpeed::Frames5 => 5,
+            ScrollSpeed::Frames25 => 25,
+            ScrollSpeed::Frames64 => 64,
+            ScrollSpeed::Frames128 => 128,
+            ScrollSpeed::Frames256 => 256,
+        }
+    }
+
+    /// the time between two steps, approximating the frame rate of the SSD1306
+    pub fn step_interval(&self) -> Duration {
+        DISPLAY_FRAME * self.frames()
+    }
+}
+
+/// scroll the region of the frame just rendered in software, as far as it moved after `steps` steps.
+/// Returns `false` if the `display` can't read back its frame, leaving it as it is.
+pub(crate) fn scroll_in_software<B: Backend>(display: &mut B, scroll: &Scroll, steps: u64) -> Result<bool, DisplayError> {
+    let size = display.bounding_box().size;
+    if display.read_pixel(Point::zero()).is_none() || size.width == 0 || size.height == 0 {
+        return Ok(false);
+    }
+
+    let horizontal = (steps % size.width as u64) as u32;
+    let up = |rows: u8| (steps % size.height as u64 * rows as u64 % size.height as u64) as u32;
+    let (shift, vertical) = match scroll.direction {
+        ScrollDirection::Left => (size.width - horizontal, 0),
+        ScrollDirection::Right => (horizontal, 0),
+        ScrollDirection::DiagonalLeft(rows) => (size.width - horizontal, up(rows)),
+        ScrollDirection::DiagonalRight(rows) => (horizontal, up(rows)),
+    };
+
+    for y in scroll.rows(size.height) {
+        rotate(display, size.width, shift, |x| Point::new(x as i32, y as i32))?;
+    }
+    // moving up by `vertical` rows is moving down by the rest of the height
+    if vertical > 0 {
+        for x in 0..size.width {
+            rotate(display, size.height, size.height - vertical, |y| Point::new(x as i32, y as i32))?;
+        }
+    }
+
+    Ok(true)
+}
+
+/// move the `length` pixels of a line forward by `shift`, wrapping around, in place: each cycle of positions is
+/// followed back to its start, so every pixel is read before it is overwritten.
+fn rotate<B: Backend>(display: &mut B, length: u32, shift: u32, point: impl Fn(u32) -> Point) -> Result<(), DisplayError> {
+    let shift = shift % length;
+    if shift == 0 {
+        return Ok(());
+    }
+
+    let color = |display: &B, position: u32| display.read_pixel(point(position)).unwrap_or_default();
+    for start in 0..gcd(length, shift) {
+        let first = color(display, start);
+        let mut position = start;
+        loop {
+            let source = (position + length - shift) % length;
+            if source == start {
+                display.draw_iter(once(Pixel(point(position), first)))?;
+                break;
+            }
+            let moved = color(display, source);
+            display.draw_iter(once(Pixel(point(position), moved)))?;
+            position = source;
+        }
+    }
+
+    Ok(())
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
@@ -0,0 +1,283 @@
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat, DisplayError};
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::Pixel;
+use ssd1306::command::{AddrMode, CommandAsync, HScrollDir, NFrames, VHScrollDir, VcomhLevel};
+use ssd1306::rotation::DisplayRotation;
+use ssd1306::size::DisplaySizeAsync;
+use crate::backend::Backend;
+use crate::frame_buffer::FrameBuffer;
+use crate::orientation::Orientation;
+use crate::scroll::{Scroll, ScrollDirection, ScrollSpeed};
+
+/// the contrast set by `init`, the one of the `Brightness::NORMAL` of the driver
+const DEFAULT_CONTRAST: u8 = 0x5F;
+
+/// a [Backend] of a single SSD1306, which scrolls the [Scroll] regions of the pages in hardware.
+///
+/// Instead of a buffered `Ssd1306Async` it drives the interface itself: the frame (1 KiB) is kept in the layout of the
+/// memory of the SSD1306 and the changed part of it is sent on each flush, the scroll commands go straight through the
+/// interface. Any `DisplayRotation` and [Orientation] is applied while drawing into the frame.
+///
+/// As it keeps the frame itself, the [PageRotator](crate::PageRotator) can read it back, to
+/// [capture](crate::PageController::capture) it and to scroll in software where the hardware can't: the display only
+/// scrolls in hardware with an [Orientation] of [`Rotate0`](Orientation::Rotate0).
+///
+/// ## type parameters
+/// - `DI`: the interface of the display, e.g. an `I2CInterface`
+/// - `SIZE`: the size of the display, e.g. `DisplaySize128x64`
+///
+/// ## example
+/// ```rust,ignore
+/// let display = ScrollingSsd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+///
+/// let mut pagr = PageRotator::<5, _>::new(display);
+/// ```
+pub struct ScrollingSsd1306<DI, SIZE> {
+    interface: DI,
+    size: SIZE,
+    orientation: Orientation,
+    /// the frame as stored in the memory of the display. An SSD1306 has at most 128x64 pixels.
+    frame: FrameBuffer<128, 8>,
+    /// the top left and bottom right pixel of the frame changed since the last flush, as stored
+    changed: Option<(Point, Point)>,
+}
+
+impl<DI, SIZE> ScrollingSsd1306<DI, SIZE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    /// create a new [ScrollingSsd1306] of the display behind the `interface`, as an `Ssd1306Async` is created
+    pub fn new(interface: DI, size: SIZE, rotation: DisplayRotation) -> Self {
+        Self {
+            interface,
+            size,
+            orientation: orientation(rotation),
+            frame: FrameBuffer::new(),
+            changed: None,
+        }
+    }
+
+    /// give back the interface
+    pub fn release(self) -> DI {
+        self.interface
+    }
+
+    /// the size of the display as mounted, regardless of its [Orientation]
+    fn native_size() -> Size {
+        Size::new(SIZE::WIDTH as u32, SIZE::HEIGHT as u32)
+    }
+
+    /// send the whole frame on the next flush, e.g. after the memory of the display got out of sync
+    fn change_all(&mut self) {
+        let size = Self::native_size();
+        self.changed = Some((Point::zero(), Point::new(size.width as i32 - 1, size.height as i32 - 1)));
+    }
+
+    async fn send(&mut self, commands: &[CommandAsync]) -> Result<(), DisplayError> {
+        for command in commands {
+            command.send(&mut self.interface).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<DI, SIZE> OriginDimensions for ScrollingSsd1306<DI, SIZE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    fn size(&self) -> Size {
+        self.orientation.size(Self::native_size())
+    }
+}
+
+impl<DI, SIZE> DrawTarget for ScrollingSsd1306<DI, SIZE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let size = self.size();
+        let native_size = Self::native_size();
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.y < 0 || point.x as u32 >= size.width || point.y as u32 >= size.height {
+                continue;
+            }
+
+            let native = self.orientation.to_native(point, native_size);
+            self.frame.set_pixel(native, color);
+            self.changed = Some(match self.changed {
+                Some((start, end)) => (start.component_min(native), end.component_max(native)),
+                None => (native, native),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<DI, SIZE> Backend for ScrollingSsd1306<DI, SIZE>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        // the same setup as the `init` of a buffered `Ssd1306Async`, the rotation is applied while drawing
+        self.send(&[
+            CommandAsync::DisplayOn(false),
+            CommandAsync::DisplayClockDiv(0x8, 0x0),
+            CommandAsync::Multiplex(SIZE::HEIGHT - 1),
+            CommandAsync::DisplayOffset(0),
+            CommandAsync::StartLine(0),
+            CommandAsync::ChargePump(true),
+            CommandAsync::AddressMode(AddrMode::Horizontal),
+        ])
+        .await?;
+        self.size.configure(&mut self.interface).await?;
+        self.send(&[
+            CommandAsync::SegmentRemap(true),
+            CommandAsync::ReverseComDir(true),
+            CommandAsync::PreChargePeriod(1, 0x2),
+            CommandAsync::Contrast(DEFAULT_CONTRAST),
+            CommandAsync::VcomhDeselect(VcomhLevel::Auto),
+            CommandAsync::AllOn(false),
+            CommandAsync::Invert(false),
+            CommandAsync::EnableScroll(false),
+            CommandAsync::DisplayOn(true),
+        ])
+        .await?;
+
+        self.clear_buffer();
+        Ok(())
+    }
+
+    fn clear_buffer(&mut self) {
+        self.frame.clear_buffer();
+        self.change_all();
+    }
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        let Some((start, end)) = self.changed else {
+            return Ok(());
+        };
+
+        let (x, y) = (SIZE::OFFSETX, SIZE::OFFSETY);
+        self.send(&[
+            CommandAsync::ColumnAddress(x + start.x as u8, x + end.x as u8),
+            CommandAsync::PageAddress((y + start.y as u8).into(), (y + end.y as u8).into()),
+        ])
+        .await?;
+        let columns = start.x as usize..=end.x as usize;
+        for page in &self.frame.pages()[start.y as usize / 8..=end.y as usize / 8] {
+            self.interface.send_data(DataFormat::U8(&page[columns.clone()])).await?;
+        }
+
+        // a failed flush sends the changes again with the next one
+        self.changed = None;
+        Ok(())
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> Result<(), DisplayError> {
+        // the lowest precharge period is used by the SSD1306 for its dimmest preset only
+        let precharge = if brightness == 0 { 0x1 } else { 0x2 };
+        self.send(&[CommandAsync::PreChargePeriod(1, precharge), CommandAsync::Contrast(brightness)]).await
+    }
+
+    async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), DisplayError> {
+        // the content drawn before doesn't fit the new orientation, the page is rendered again anyway
+        self.orientation = orientation;
+        self.clear_buffer();
+        Ok(())
+    }
+
+    async fn set_inverted(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        self.send(&[CommandAsync::Invert(inverted)]).await
+    }
+
+    async fn start_scroll(&mut self, scroll: &Scroll) -> Result<bool, DisplayError> {
+        if self.orientation != Orientation::Rotate0 {
+            return Ok(false);
+        }
+
+        let height = SIZE::HEIGHT as u32;
+        let rows = scroll.rows(height);
+        if rows.is_empty() {
+            return Ok(false);
+        }
+
+        // the driver addresses the pages by their first pixel row
+        let start = (rows.start as u8).into();
+        let end = ((rows.end - 1) as u8).into();
+        let speed = frames(scroll.speed);
+        let setup = match scroll.direction {
+            ScrollDirection::Left => CommandAsync::HScrollSetup(HScrollDir::RightToLeft, start, end, speed),
+            ScrollDirection::Right => CommandAsync::HScrollSetup(HScrollDir::LeftToRight, start, end, speed),
+            ScrollDirection::DiagonalLeft(rows) => {
+                CommandAsync::VHScrollSetup(VHScrollDir::VerticalLeft, start, end, speed, rows % height as u8)
+            }
+            ScrollDirection::DiagonalRight(rows) => {
+                CommandAsync::VHScrollSetup(VHScrollDir::VerticalRight, start, end, speed, rows % height as u8)
+            }
+        };
+
+        // the scroll has to be stopped before it is set up again
+        self.send(&[
+            CommandAsync::EnableScroll(false),
+            CommandAsync::VScrollArea(0, height as u8),
+            setup,
+            CommandAsync::EnableScroll(true),
+        ])
+        .await?;
+
+        Ok(true)
+    }
+
+    async fn stop_scroll(&mut self) -> Result<(), DisplayError> {
+        self.send(&[CommandAsync::EnableScroll(false)]).await?;
+        // the memory of the display moved along with the scroll, so it is written anew
+        self.change_all();
+        Ok(())
+    }
+
+    fn read_pixel(&self, point: Point) -> Option<BinaryColor> {
+        let size = self.size();
+        if point.x < 0 || point.y < 0 || point.x as u32 >= size.width || point.y as u32 >= size.height {
+            return None;
+        }
+
+        self.frame.pixel(self.orientation.to_native(point, Self::native_size()))
+    }
+}
+
+/// the [Orientation] showing the content as the driver does with the given rotation
+fn orientation(rotation: DisplayRotation) -> Orientation {
+    match rotation {
+        DisplayRotation::Rotate0 => Orientation::Rotate0,
+        DisplayRotation::Rotate90 => Orientation::Rotate90,
+        DisplayRotation::Rotate180 => Orientation::Rotate180,
+        DisplayRotation::Rotate270 => Orientation::Rotate270,
+    }
+}
+
+fn frames(speed: ScrollSpeed) -> NFrames {
+    match speed {
+        ScrollSpeed::Frames2 => NFrames::F2,
+        ScrollSpeed::Frames3 => NFrames::F3,
+        ScrollSpeed::Frames4 => NFrames::F4,
+        ScrollSpeed::Frames5 => NFrames::F5,
+        ScrollSpeed::Frames25 => NFrames::F25,
+        ScrollSpeed::Frames64 => NFrames::F64,
+        ScrollSpeed::Frames128 => NFrames::F128,
+        ScrollSpeed::Frames256 => NFrames::F256,
+    }
+}
@@ -0,0 +1,180 @@
+use display_interface::DisplayError;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{Dimensions, OriginDimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::Pixel;
+use crate::backend::{combined_result, Backend};
+use crate::frame_buffer::FrameBuffer;
+use crate::orientation::Orientation;
+
+/// define how the displays of a [SpanBackend] are arranged to form the large canvas.
+///
+/// the displays are placed in the order they are passed to the [SpanBackend].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SpanLayout<const COUNT: usize> {
+    /// side by side, from left to right
+    Horizontal,
+    /// on top of each other, from top to bottom
+    Vertical,
+    /// in rows of `columns` displays, filled from left to right and top to bottom.
+    /// All displays are expected to have the same size.
+    Grid { columns: usize },
+    /// each display shows the area of the canvas starting at the given offset
+    Offsets([Point; COUNT]),
+}
+
+/// a [Backend] spanning one large canvas across several displays.
+///
+/// The pages render onto an internal [FrameBuffer] of the size of the whole canvas. On flush
+/// every display gets its part of the canvas, as defined by the [SpanLayout]. An [Orientation] set at runtime
+/// rotates the whole canvas, e.g. a horizontal row of displays shows a tall canvas when rotated by 90°.
+///
+/// The displays are isolated from each other: if one display fails to initialize or to flush,
+/// the others still get updated. Only if _all_ displays fail the error is passed on to the
+/// [PageRotator](crate::PageRotator). The last error per display can be checked via
+/// [`display_error`](SpanBackend::display_error).
+///
+/// ## type parameters
+/// - `D`: the type of the displays, any [Backend] (e.g. a buffered `Ssd1306Async`)
+/// - `COUNT`: the amount of displays
+/// - `WIDTH`, `PAGES`: the dimension of the whole canvas: width in pixels, height in pages of 8 pixels
+///
+/// ## example
+/// four 128x32 displays side by side, forming a 512x32 canvas:
+/// ```rust,ignore
+/// let wall = SpanBackend::<_, 4, 512, 4>::new([first, second, third, fourth], SpanLayout::Horizontal);
+/// let mut pagr = PageRotator::<5, _>::new(wall);
+/// ```
+pub struct SpanBackend<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> {
+    displays: [D; COUNT],
+    areas: [Rectangle; COUNT],
+    errors: [Option<DisplayError>; COUNT],
+    frame: FrameBuffer<WIDTH, PAGES>,
+}
+
+impl<D: Dimensions, const COUNT: usize, const WIDTH: usize, const PAGES: usize> SpanBackend<D, COUNT, WIDTH, PAGES> {
+    /// create a new [SpanBackend] for the given displays, arranged according to the `layout`
+    pub fn new(displays: [D; COUNT], layout: SpanLayout<COUNT>) -> Self {
+        let sizes: [Size; COUNT] = core::array::from_fn(|index| displays[index].bounding_box().size);
+        let offsets = match layout {
+            SpanLayout::Horizontal => Self::horizontal_offsets(&sizes),
+            SpanLayout::Vertical => Self::vertical_offsets(&sizes),
+            SpanLayout::Grid { columns } => Self::grid_offsets(&sizes, columns),
+            SpanLayout::Offsets(offsets) => offsets,
+        };
+
+        Self {
+            displays,
+            areas: core::array::from_fn(|index| Rectangle::new(offsets[index], sizes[index])),
+            errors: core::array::from_fn(|_| None),
+            frame: FrameBuffer::new(),
+        }
+    }
+
+    /// the error of the last operation on the display with the given index, `None` if it succeeded
+    pub fn display_error(&self, index: usize) -> Option<&DisplayError> {
+        self.errors.get(index).and_then(Option::as_ref)
+    }
+
+    fn horizontal_offsets(sizes: &[Size; COUNT]) -> [Point; COUNT] {
+        let mut x = 0;
+        sizes.map(|size| {
+            let offset = Point::new(x, 0);
+            x += size.width as i32;
+            offset
+        })
+    }
+
+    fn vertical_offsets(sizes: &[Size; COUNT]) -> [Point; COUNT] {
+        let mut y = 0;
+        sizes.map(|size| {
+            let offset = Point::new(0, y);
+            y += size.height as i32;
+            offset
+        })
+    }
+
+    fn grid_offsets(sizes: &[Size; COUNT], columns: usize) -> [Point; COUNT] {
+        let columns = columns.max(1);
+        let cell = sizes.first().copied().unwrap_or_default();
+
+        core::array::from_fn(|index| {
+            Point::new(
+                (index % columns) as i32 * cell.width as i32,
+                (index / columns) as i32 * cell.height as i32,
+            )
+        })
+    }
+}
+
+impl<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> OriginDimensions for SpanBackend<D, COUNT, WIDTH, PAGES> {
+    fn size(&self) -> Size {
+        self.frame.size()
+    }
+}
+
+impl<D, const COUNT: usize, const WIDTH: usize, const PAGES: usize> DrawTarget for SpanBackend<D, COUNT, WIDTH, PAGES> {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.frame.draw_iter(pixels)
+    }
+}
+
+impl<D: Backend, const COUNT: usize, const WIDTH: usize, const PAGES: usize> Backend for SpanBackend<D, COUNT, WIDTH, PAGES> {
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.init().await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    fn clear_buffer(&mut self) {
+        self.frame.clear_buffer();
+    }
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        for ((display, area), error) in self.displays.iter_mut().zip(self.areas.iter()).zip(self.errors.iter_mut()) {
+            display.clear_buffer();
+            let result = match self.frame.draw_area(area, display) {
+                Ok(()) => display.flush().await,
+                Err(e) => Err(e),
+            };
+            *error = result.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.set_brightness(brightness).await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    async fn set_orientation(&mut self, orientation: Orientation) -> Result<(), DisplayError> {
+        self.frame.set_orientation(orientation);
+        Ok(())
+    }
+
+    async fn set_inverted(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        for (display, error) in self.displays.iter_mut().zip(self.errors.iter_mut()) {
+            *error = display.set_inverted(inverted).await.err();
+        }
+
+        combined_result(&self.errors)
+    }
+
+    fn read_pixel(&self, point: Point) -> Option<BinaryColor> {
+        self.frame.pixel(point)
+    }
+}
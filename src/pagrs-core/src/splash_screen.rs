@@ -1,16 +1,13 @@
-use display_interface::AsyncWriteOnlyDataCommand;
 use embassy_time::{Duration, Timer};
 use embedded_graphics::primitives::{Polyline, Primitive, PrimitiveStyle};
-use embedded_graphics_core::geometry::{Dimensions, Point, Size};
+use embedded_graphics_core::geometry::{Point, Size};
 use embedded_graphics_core::pixelcolor::BinaryColor;
 use embedded_graphics_core::primitives::Rectangle;
 use embedded_graphics_core::Drawable;
 use embedded_layout::align::{horizontal, vertical, Align};
 use embedded_layout::layout::linear::{FixedMargin, LinearLayout};
 use embedded_layout::object_chain::Chain;
-use ssd1306::mode::BufferedGraphicsModeAsync;
-use ssd1306::size::DisplaySizeAsync;
-use ssd1306::Ssd1306Async;
+use crate::backend::Backend;
 
 static BRACKET_POINTS_LEFT: [Point; 4] = [
     Point::new(2, 0),
@@ -28,18 +25,12 @@ static BRACKET_POINTS_RIGHT: [Point; 4] = [
 /// shows an icon for `pagrs`,
 /// used as short splashscreen by the [PageRotator](crate::PageRotator) before starting to
 /// rotate the actual [Pages](crate::Page)
-pub(crate) struct SplashScreen<'a, DI, SIZE, MODE> {
-    display: &'a mut Ssd1306Async<DI, SIZE, MODE>,
+pub(crate) struct SplashScreen<'a, B> {
+    display: &'a mut B,
 }
 
-impl<'a, DI, SIZE> SplashScreen<'a, DI, SIZE, BufferedGraphicsModeAsync<SIZE>>
-where
-    DI: AsyncWriteOnlyDataCommand,
-    SIZE: DisplaySizeAsync,
-{
-    pub(crate) fn new(
-        display: &'a mut Ssd1306Async<DI, SIZE, BufferedGraphicsModeAsync<SIZE>>,
-    ) -> Self {
+impl<'a, B: Backend> SplashScreen<'a, B> {
+    pub(crate) fn new(display: &'a mut B) -> Self {
         Self { display }
     }
 
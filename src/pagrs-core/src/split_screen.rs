@@ -0,0 +1,258 @@
+use display_interface::DisplayError;
+use embassy_time::{Duration, Instant};
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{Dimensions, Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::Rectangle;
+use heapless::Vec;
+use crate::viewport::Viewport;
+use crate::{InputEvent, Page, Refresh};
+
+/// define how a [SplitScreen] divides the display into regions.
+///
+/// the weights define the relative size of each row or column: `&[1, 2]` gives the first
+/// region one third and the second region two thirds of the space.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SplitLayout<'w> {
+    /// one region per row, each spanning the whole width
+    Rows(&'w [u32]),
+    /// one region per column, each spanning the whole height
+    Columns(&'w [u32]),
+    /// one region per cell, filled from left to right and top to bottom
+    Grid { columns: &'w [u32], rows: &'w [u32] },
+}
+
+impl SplitLayout<'_> {
+    /// the region of the child with the given index inside the `area`,
+    /// `None` if the layout has no region for that index
+    fn region(&self, index: usize, area: &Rectangle) -> Option<Rectangle> {
+        match self {
+            SplitLayout::Rows(rows) => {
+                let (top, height) = Self::section(rows, index, area.size.height)?;
+                Some(Rectangle::new(area.top_left + Point::new(0, top), Size::new(area.size.width, height)))
+            }
+            SplitLayout::Columns(columns) => {
+                let (left, width) = Self::section(columns, index, area.size.width)?;
+                Some(Rectangle::new(area.top_left + Point::new(left, 0), Size::new(width, area.size.height)))
+            }
+            SplitLayout::Grid { columns, rows } => {
+                if columns.is_empty() {
+                    return None;
+                }
+
+                let (left, width) = Self::section(columns, index % columns.len(), area.size.width)?;
+                let (top, height) = Self::section(rows, index / columns.len(), area.size.height)?;
+                Some(Rectangle::new(area.top_left + Point::new(left, top), Size::new(width, height)))
+            }
+        }
+    }
+
+    /// start and length of the section with the given index, splitting `length` according to the `weights`
+    fn section(weights: &[u32], index: usize, length: u32) -> Option<(i32, u32)> {
+        if index >= weights.len() {
+            return None;
+        }
+
+        let total: u32 = weights.iter().sum();
+        if total == 0 {
+            return None;
+        }
+
+        let before: u32 = weights[..index].iter().sum();
+        let start = length * before / total;
+        let end = length * (before + weights[index]) / total;
+
+        Some((start as i32, end - start))
+    }
+}
+
+struct Region<'a, D> {
+    page: &'a mut dyn for<'c> Page<Viewport<'c, D>>,
+    interval: Option<Duration>,
+    next_render: Instant,
+}
+
+/// show several pages at the same time, each in its own region of the display.
+///
+/// Each child page gets a [Viewport] of its region: its [`bounding_box`](embedded_graphics_core::geometry::Dimensions::bounding_box)
+/// is the size of the region and drawing outside the region is clipped.
+/// The lifecycle calls are forwarded to all children and each child is rendered with its own
+/// [`refresh`](Page::refresh), only clearing its own region. Children rendered on demand are rendered when activated
+/// and after consuming an input event.
+/// [Input events](crate::InputEvent) are offered to the children in order of registration, until one consumes it.
+///
+/// A [SplitScreen] can be registered to the [PageRotator](crate::PageRotator) as well as be used as child page
+/// of another [SplitScreen] or a [Carousel](crate::Carousel).
+///
+/// ## type parameters
+/// - `D`: the display type, e.g. the [Backend](crate::Backend) of the [PageRotator](crate::PageRotator)
+/// - `PAGE_COUNT`: the maximum amount of child pages
+///
+/// ## example
+/// clock on the left, temperature on the right, the clock taking two thirds of the width:
+/// ```rust,ignore
+/// let mut split = SplitScreen::<_, 2>::new(SplitLayout::Columns(&[2, 1]));
+/// let _ = split.add_page(&mut clock);
+/// let _ = split.add_page(&mut temperature);
+///
+/// let _ = pagr.add_page(&mut split);
+/// ```
+pub struct SplitScreen<'a, D, const PAGE_COUNT: usize> {
+    layout: SplitLayout<'a>,
+    regions: Vec<Region<'a, D>, PAGE_COUNT>,
+}
+
+impl<'a, D, const PAGE_COUNT: usize> SplitScreen<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    /// create a new, empty [SplitScreen] dividing the display according to the `layout`
+    pub fn new(layout: SplitLayout<'a>) -> Self {
+        Self {
+            layout,
+            regions: Vec::new(),
+        }
+    }
+
+    /// register a new [Page] for the next free region of the layout.
+    /// Returns the page back if already `PAGE_COUNT` pages are registered.
+    pub fn add_page<P: for<'c> Page<Viewport<'c, D>>>(&mut self, page: &'a mut P) -> Result<(), &'a mut P> {
+        if self.regions.is_full() {
+            return Err(page);
+        }
+
+        let region = Region {
+            interval: Page::<Viewport<'_, D>>::refresh(page).interval(),
+            page,
+            next_render: Instant::MIN,
+        };
+        // can't fail, there is room left
+        let _ = self.regions.push(region);
+
+        Ok(())
+    }
+
+    fn activate_regions(&mut self) -> Result<(), DisplayError> {
+        for region in self.regions.iter_mut() {
+            region.page.activated()?;
+            region.next_render = Instant::MIN;
+        }
+
+        Ok(())
+    }
+
+    fn render_regions(&mut self, viewport: &mut Viewport<'_, D>) -> Result<(), DisplayError> {
+        let area = viewport.bounding_box();
+        let now = Instant::now();
+        // the frames of the split screen and the children's intervals don't line up exactly,
+        // so a child is rendered in the frame closest to when it is due
+        let slack = self.fastest_refresh().interval().map_or(Duration::from_ticks(0), |interval| interval / 2);
+
+        for (index, region) in self.regions.iter_mut().enumerate() {
+            if now + slack < region.next_render {
+                continue;
+            }
+            region.next_render = match region.interval {
+                Some(interval) => now + interval,
+                None => Instant::MAX,
+            };
+
+            let Some(bounds) = self.layout.region(index, &area) else {
+                continue;
+            };
+            viewport.fill_solid(&bounds, BinaryColor::Off)?;
+            region.page.render(&mut viewport.viewport(&bounds))?;
+        }
+
+        Ok(())
+    }
+
+    fn deactivate_regions(&mut self) -> Result<(), DisplayError> {
+        for region in self.regions.iter_mut() {
+            region.page.deactivated()?;
+        }
+
+        Ok(())
+    }
+
+    /// offer the event to the child pages in order of registration, until one consumes it
+    fn forward_input(&mut self, event: InputEvent) -> bool {
+        for region in self.regions.iter_mut() {
+            if region.page.input(event) {
+                // show the reaction of the page right away
+                region.next_render = Instant::MIN;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn fastest_refresh(&self) -> Refresh {
+        self.regions
+            .iter()
+            .map(|region| region.page.refresh())
+            .reduce(Refresh::fastest)
+            .unwrap_or(Refresh::OnDemand)
+    }
+}
+
+impl<'a, D, const PAGE_COUNT: usize> Page<D> for SplitScreen<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.activate_regions()
+    }
+
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let area = display.bounding_box();
+        self.render_regions(&mut Viewport::new(display, &area))
+    }
+
+    fn deactivated(&mut self) -> Result<(), DisplayError> {
+        self.deactivate_regions()
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.fastest_refresh()
+    }
+
+    fn clear_each_frame(&self) -> bool {
+        false
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        self.forward_input(event)
+    }
+}
+
+impl<'a, 'c, D, const PAGE_COUNT: usize> Page<Viewport<'c, D>> for SplitScreen<'a, D, PAGE_COUNT>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.activate_regions()
+    }
+
+    fn render(&mut self, display: &mut Viewport<'c, D>) -> Result<(), DisplayError> {
+        self.render_regions(display)
+    }
+
+    fn deactivated(&mut self) -> Result<(), DisplayError> {
+        self.deactivate_regions()
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.fastest_refresh()
+    }
+
+    fn clear_each_frame(&self) -> bool {
+        false
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        self.forward_input(event)
+    }
+}
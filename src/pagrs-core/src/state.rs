@@ -0,0 +1,73 @@
+/// version of the encoding, stored as first byte to detect incompatible data
+const STATE_VERSION: u8 = 1;
+
+const FLAG_PAUSED: u8 = 0b01;
+const FLAG_BRIGHTNESS: u8 = 0b10;
+
+/// the state of the rotation, which is persisted via a [StateStorage](crate::StateStorage)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct RotationState {
+    /// index of the page currently shown
+    pub index: usize,
+    /// if the rotation is paused, keeping the current page
+    pub paused: bool,
+    /// the brightness set via the [PageController](crate::PageController), `None` if never changed
+    pub brightness: Option<u8>,
+    /// bit mask of the pages which are skipped by the rotation
+    pub disabled_pages: u32,
+}
+
+impl RotationState {
+    pub const ENCODED_SIZE: usize = 8;
+
+    pub fn is_disabled(&self, index: usize) -> bool {
+        index < 32 && self.disabled_pages & (1 << index) != 0
+    }
+
+    pub fn set_disabled(&mut self, index: usize, disabled: bool) {
+        if index >= 32 {
+            return;
+        }
+
+        if disabled {
+            self.disabled_pages |= 1 << index;
+        } else {
+            self.disabled_pages &= !(1 << index);
+        }
+    }
+
+    pub fn encode(&self) -> [u8; Self::ENCODED_SIZE] {
+        let mut flags = 0;
+        if self.paused {
+            flags |= FLAG_PAUSED;
+        }
+        if self.brightness.is_some() {
+            flags |= FLAG_BRIGHTNESS;
+        }
+
+        let disabled = self.disabled_pages.to_le_bytes();
+        [
+            STATE_VERSION,
+            self.index.min(u8::MAX as usize) as u8,
+            flags,
+            self.brightness.unwrap_or(0),
+            disabled[0],
+            disabled[1],
+            disabled[2],
+            disabled[3],
+        ]
+    }
+
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::ENCODED_SIZE || data[0] != STATE_VERSION {
+            return None;
+        }
+
+        Some(Self {
+            index: data[1] as usize,
+            paused: data[2] & FLAG_PAUSED != 0,
+            brightness: (data[2] & FLAG_BRIGHTNESS != 0).then_some(data[3]),
+            disabled_pages: u32::from_le_bytes([data[4], data[5], data[6], data[7]]),
+        })
+    }
+}
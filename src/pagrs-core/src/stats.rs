@@ -0,0 +1,77 @@
+use embassy_time::Duration;
+
+/// timing statistics of the frames shown of a single page, as returned by [`PageController::frame_stats`](crate::PageController::frame_stats).
+///
+/// Use them to tune the frames per second of a page or the bus speed of the display: if rendering and flushing a frame
+/// takes longer than the interval between two frames, the page can't keep up with its framerate and ticks are missed.
+/// The frames are then shown as fast as possible, which is visible as stutter.
+///
+/// ## example
+/// ```rust
+/// use embassy_time::Duration;
+/// use pagrs_core::FrameStats;
+///
+/// let stats = FrameStats {
+///     frames: 50,
+///     shown: Duration::from_secs(5),
+///     render_total: Duration::from_millis(100),
+///     flush_total: Duration::from_millis(400),
+///     ..FrameStats::default()
+/// };
+///
+/// assert_eq!(stats.frames_per_second(), 10);
+/// assert_eq!(stats.average_render(), Duration::from_millis(2));
+/// assert_eq!(stats.average_flush(), Duration::from_millis(8));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FrameStats {
+    /// the amount of frames rendered and flushed
+    pub frames: u32,
+    /// the amount of frames started late, because the previous frame took longer than the frame interval
+    pub missed_ticks: u32,
+    /// how long the page was shown in total
+    pub shown: Duration,
+    /// the sum of the time spent in `render()` of the page
+    pub render_total: Duration,
+    /// the longest time spent in `render()` for a single frame
+    pub render_max: Duration,
+    /// the sum of the time spent flushing the frames to the display
+    pub flush_total: Duration,
+    /// the longest time spent flushing a single frame to the display
+    pub flush_max: Duration,
+}
+
+impl FrameStats {
+    /// the average time spent in `render()` per frame
+    pub fn average_render(&self) -> Duration {
+        self.render_total / self.frames.max(1)
+    }
+
+    /// the average time spent flushing a frame to the display
+    pub fn average_flush(&self) -> Duration {
+        self.flush_total / self.frames.max(1)
+    }
+
+    /// the frames per second achieved while the page was shown
+    pub fn frames_per_second(&self) -> u32 {
+        match self.shown.as_millis() {
+            0 => 0,
+            millis => (self.frames as u64 * 1000 / millis) as u32,
+        }
+    }
+
+    /// add a frame, which took `render` and `flush` with `interval` between two frames, `None` if rendered on demand
+    pub(crate) fn record(&mut self, render: Duration, flush: Duration, interval: Option<Duration>) {
+        self.frames = self.frames.saturating_add(1);
+        self.render_total += render;
+        self.render_max = self.render_max.max(render);
+        self.flush_total += flush;
+        self.flush_max = self.flush_max.max(flush);
+
+        if let Some(interval) = interval {
+            let ticks = (render + flush).as_ticks() / interval.as_ticks().max(1);
+            self.missed_ticks = self.missed_ticks.saturating_add(ticks as u32);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use embedded_storage::nor_flash::NorFlash;
+
+/// size of a single record of the [NorFlashStorage], including the header
+const RECORD_SIZE: usize = 32;
+/// the header of a record: magic, length, generation of the sector (2 bytes) and checksum
+const HEADER_SIZE: usize = 5;
+/// marks a record of the [NorFlashStorage]. Erased flash reads as `0xFF`.
+const RECORD_MAGIC: u8 = 0x9A;
+const ERASED: u8 = 0xFF;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// errors reported by a [StateStorage]
+pub enum StorageError {
+    /// the data does not fit into the storage
+    TooLarge,
+    /// the underlying memory reported an error
+    Memory,
+}
+
+/// a small, persistent memory used by the [PageRotator](crate::PageRotator) to keep its state
+/// (current page, paused, brightness, disabled pages) across reboots.
+///
+/// **info**: changes via the [PageController](crate::PageController) are saved right away. The page reached by the
+/// rotation itself is saved at most once every 10 minutes by default, see
+/// [`save_rotation_every`](crate::PageRotator::save_rotation_every): after a restart the rotation continues with the
+/// page saved last, which may be a few pages behind.
+///
+/// implementations exist for:
+/// - [NorFlashStorage]: any NOR flash implementing [embedded_storage::nor_flash::NorFlash]
+/// - [MemoryStorage]: plain RAM, e.g. for tests or RAM regions surviving a soft reset
+pub trait StateStorage {
+    /// read the last saved data into `buffer`. Returns the amount of bytes read, `0` if nothing was saved yet.
+    fn load(&mut self, buffer: &mut [u8]) -> Result<usize, StorageError>;
+
+    /// persist the `data`, replacing whatever was saved before
+    fn save(&mut self, data: &[u8]) -> Result<(), StorageError>;
+}
+
+/// a [StateStorage] keeping the data in RAM.
+///
+/// ## type parameters
+/// - `SIZE`: the maximum amount of bytes that can be stored
+///
+/// ## example
+/// ```rust
+/// use pagrs_core::{MemoryStorage, StateStorage};
+///
+/// let mut storage = MemoryStorage::<8>::new();
+/// storage.save(&[1, 2, 3]).unwrap();
+///
+/// let mut buffer = [0; 8];
+/// assert_eq!(storage.load(&mut buffer), Ok(3));
+/// assert_eq!(&buffer[..3], &[1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryStorage<const SIZE: usize> {
+    data: [u8; SIZE],
+    length: usize,
+}
+
+impl<const SIZE: usize> MemoryStorage<SIZE> {
+    /// create a new, empty [MemoryStorage]
+    pub const fn new() -> Self {
+        Self {
+            data: [0; SIZE],
+            length: 0,
+        }
+    }
+}
+
+impl<const SIZE: usize> Default for MemoryStorage<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> StateStorage for MemoryStorage<SIZE> {
+    fn load(&mut self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        let length = self.length.min(buffer.len());
+        buffer[..length].copy_from_slice(&self.data[..length]);
+
+        Ok(length)
+    }
+
+    fn save(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        if data.len() > SIZE {
+            return Err(StorageError::TooLarge);
+        }
+
+        self.data[..data.len()].copy_from_slice(data);
+        self.length = data.len();
+
+        Ok(())
+    }
+}
+
+/// a [StateStorage] on a NOR flash, using two erase sectors starting at `offset`.
+///
+/// To spare the flash, each save appends a new record of 32 bytes to the current sector. Once it is full, the other
+/// sector gets erased and continued with, so the last record saved survives a power loss while erasing or writing.
+/// Records only partially written are detected by their checksum and ignored. At most 27 bytes can be saved and the
+/// flash's `WRITE_SIZE` and `READ_SIZE` must divide 32.
+///
+/// ## example
+/// ```rust,ignore
+/// // use the last two sectors of the 2MB flash of a raspberry pico
+/// let flash = embassy_rp::flash::Flash::<_, Blocking, { 2 * 1024 * 1024 }>::new_blocking(p.FLASH);
+/// let mut storage = NorFlashStorage::new(flash, 2 * 1024 * 1024 - 2 * 4096);
+/// ```
+pub struct NorFlashStorage<F> {
+    flash: F,
+    offset: u32,
+    /// where the next record goes, known after the sectors were scanned once
+    next_record: Option<Slot>,
+}
+
+/// the position of a record in the two sectors of a [NorFlashStorage]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Slot {
+    sector: u32,
+    index: u32,
+    /// counts up each time the storage continues with the other sector, to tell which sector is the newer one
+    generation: u16,
+}
+
+impl<F: NorFlash> NorFlashStorage<F> {
+    /// create a new [NorFlashStorage] using the two erase sectors starting at `offset`
+    pub fn new(flash: F, offset: u32) -> Self {
+        Self {
+            flash,
+            offset,
+            next_record: None,
+        }
+    }
+
+    /// give back the flash
+    pub fn release(self) -> F {
+        self.flash
+    }
+
+    fn record_count() -> u32 {
+        (F::ERASE_SIZE / RECORD_SIZE) as u32
+    }
+
+    fn address(&self, sector: u32, index: u32) -> u32 {
+        self.offset + sector * F::ERASE_SIZE as u32 + index * RECORD_SIZE as u32
+    }
+
+    /// find the latest valid record of both sectors, reading it into `record`. Also remembers where the next record
+    /// will go.
+    fn find_latest(&mut self, record: &mut [u8; RECORD_SIZE]) -> Result<Option<Slot>, StorageError> {
+        let mut latest: Option<Slot> = None;
+        let mut next = None;
+        let mut current = [ERASED; RECORD_SIZE];
+
+        for sector in 0..2 {
+            let mut sector_latest = None;
+            let mut free = None;
+            for index in 0..Self::record_count() {
+                self.flash
+                    .read(self.address(sector, index), &mut current)
+                    .map_err(|_| StorageError::Memory)?;
+                // a write failing before touching the flash leaves a slot erased in between, so the whole sector is
+                // scanned and the next record goes after the last one occupied
+                if current.iter().all(|byte| *byte == ERASED) {
+                    free = free.or(Some(index));
+                    continue;
+                }
+                free = None;
+
+                // records partially written are skipped, the slot stays occupied
+                if let Some(generation) = validate(&current) {
+                    sector_latest = Some(Slot { sector, index, generation });
+                }
+            }
+
+            let Some(candidate) = sector_latest else {
+                continue;
+            };
+            if latest.is_none_or(|latest| newer(candidate.generation, latest.generation)) {
+                self.flash
+                    .read(self.address(sector, candidate.index), record)
+                    .map_err(|_| StorageError::Memory)?;
+                latest = Some(candidate);
+                next = free.map(|index| Slot { index, ..candidate });
+            }
+        }
+
+        // without a free record in the latest sector, the next save continues with the other sector
+        self.next_record = Some(next.unwrap_or_else(|| match latest {
+            Some(latest) => Slot {
+                sector: 1 - latest.sector,
+                index: 0,
+                generation: latest.generation.wrapping_add(1),
+            },
+            None => Slot { sector: 0, index: 0, generation: 0 },
+        }));
+        Ok(latest)
+    }
+}
+
+impl<F: NorFlash> StateStorage for NorFlashStorage<F> {
+    fn load(&mut self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        let mut record = [ERASED; RECORD_SIZE];
+        if self.find_latest(&mut record)?.is_none() {
+            return Ok(0);
+        }
+
+        let length = (record[1] as usize).min(buffer.len());
+        buffer[..length].copy_from_slice(&record[HEADER_SIZE..HEADER_SIZE + length]);
+
+        Ok(length)
+    }
+
+    fn save(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        if data.len() > RECORD_SIZE - HEADER_SIZE {
+            return Err(StorageError::TooLarge);
+        }
+
+        let slot = match self.next_record {
+            Some(slot) => slot,
+            None => {
+                self.find_latest(&mut [ERASED; RECORD_SIZE])?;
+                self.next_record.unwrap_or(Slot { sector: 0, index: 0, generation: 0 })
+            }
+        };
+
+        // a sector is only erased when starting it over, the other one still holds the latest record
+        if slot.index == 0 {
+            let start = self.address(slot.sector, 0);
+            self.flash
+                .erase(start, start + F::ERASE_SIZE as u32)
+                .map_err(|_| StorageError::Memory)?;
+        }
+
+        let mut record = [ERASED; RECORD_SIZE];
+        record[0] = RECORD_MAGIC;
+        record[1] = data.len() as u8;
+        record[2..4].copy_from_slice(&slot.generation.to_le_bytes());
+        record[HEADER_SIZE..HEADER_SIZE + data.len()].copy_from_slice(data);
+        record[4] = checksum(&record);
+
+        // a failed write leaves the record occupied, the next save goes into the next one
+        let written = self.flash.write(self.address(slot.sector, slot.index), &record);
+        self.next_record = Some(if slot.index + 1 < Self::record_count() {
+            Slot { index: slot.index + 1, ..slot }
+        } else {
+            Slot {
+                sector: 1 - slot.sector,
+                index: 0,
+                generation: slot.generation.wrapping_add(1),
+            }
+        });
+
+        written.map_err(|_| StorageError::Memory)
+    }
+}
+
+/// the generation of the sector holding the `record`, `None` if the record is not complete
+fn validate(record: &[u8; RECORD_SIZE]) -> Option<u16> {
+    let length = record[1] as usize;
+    if record[0] != RECORD_MAGIC || length > RECORD_SIZE - HEADER_SIZE || record[4] != checksum(record) {
+        return None;
+    }
+
+    Some(u16::from_le_bytes([record[2], record[3]]))
+}
+
+/// the checksum of the header and the data of the `record`
+fn checksum(record: &[u8; RECORD_SIZE]) -> u8 {
+    let length = (record[1] as usize).min(RECORD_SIZE - HEADER_SIZE);
+    let sum = record[..4]
+        .iter()
+        .chain(&record[HEADER_SIZE..HEADER_SIZE + length])
+        .fold(0u8, |sum, byte| sum.rotate_left(1) ^ byte);
+    !sum
+}
+
+/// if the `generation` is newer than the `other` one, counting on after an overflow
+fn newer(generation: u16, other: u16) -> bool {
+    (generation.wrapping_sub(other) as i16) > 0
+}
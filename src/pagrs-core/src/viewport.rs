@@ -0,0 +1,62 @@
+use embedded_graphics::transform::Transform;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::Pixel;
+
+/// a rectangular area of a display, used as [DrawTarget] for pages shown in only a part of the display
+/// (e.g. by a [SplitScreen](crate::SplitScreen) or a [Carousel](crate::Carousel)).
+///
+/// Drawing is relative to the top left corner of the area and everything outside the area is clipped.
+/// A viewport of a viewport refers to the same display again, so nesting page containers
+/// always results in the same [DrawTarget] type.
+pub struct Viewport<'a, D> {
+    display: &'a mut D,
+    area: Rectangle,
+}
+
+impl<'a, D: DrawTarget> Viewport<'a, D> {
+    /// create a viewport for the `area` of the `display`. The area is limited to the display.
+    pub fn new(display: &'a mut D, area: &Rectangle) -> Self {
+        let area = area.intersection(&display.bounding_box());
+        Self { display, area }
+    }
+
+    /// create a viewport for the `area` inside of this viewport, using coordinates relative to this viewport
+    pub fn viewport(&mut self, area: &Rectangle) -> Viewport<'_, D> {
+        let area = area.translate(self.area.top_left).intersection(&self.area);
+        Viewport {
+            display: self.display,
+            area,
+        }
+    }
+}
+
+impl<D: DrawTarget> OriginDimensions for Viewport<'_, D> {
+    fn size(&self) -> Size {
+        self.area.size
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for Viewport<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.area;
+        self.display.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point + area.top_left, color))
+                .filter(|Pixel(point, _)| area.contains(*point)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.translate(self.area.top_left).intersection(&self.area);
+        self.display.fill_solid(&area, color)
+    }
+}
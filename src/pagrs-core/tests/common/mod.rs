@@ -0,0 +1,81 @@
+// each test uses only some of the helpers
+#![allow(dead_code)]
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use display_interface::DisplayError;
+use embedded_graphics::prelude::*;
+use embedded_graphics::pixelcolor::BinaryColor;
+use pagrs_core::{Backend, Page};
+
+pub const WIDTH: usize = 200;
+pub const HEIGHT: usize = 4;
+
+/// the rotator and its controller talk via statics, so only one test at a time may rotate
+pub fn rotating() -> MutexGuard<'static, ()> {
+    static ROTATING: Mutex<()> = Mutex::new(());
+    ROTATING.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// a display wider than the 128 pixels the capture sends at once
+pub struct Canvas {
+    pixels: [[bool; WIDTH]; HEIGHT],
+}
+
+impl Canvas {
+    pub fn new() -> Self {
+        Self {
+            pixels: [[false; WIDTH]; HEIGHT],
+        }
+    }
+}
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(WIDTH as u32, HEIGHT as u32)
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I: IntoIterator<Item = Pixel<BinaryColor>>>(&mut self, pixels: I) -> Result<(), DisplayError> {
+        for Pixel(point, color) in pixels {
+            if let Some(row) = self.pixels.get_mut(point.y as usize) {
+                if let Some(pixel) = row.get_mut(point.x as usize) {
+                    *pixel = color.is_on();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Backend for Canvas {
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
+    fn clear_buffer(&mut self) {
+        self.pixels = [[false; WIDTH]; HEIGHT];
+    }
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
+    fn read_pixel(&self, point: Point) -> Option<BinaryColor> {
+        let pixel = self.pixels.get(point.y as usize)?.get(point.x as usize)?;
+        Some(BinaryColor::from(*pixel))
+    }
+}
+
+/// lights the top left pixel and the last pixel of the second row
+pub struct Corners;
+
+impl<D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for Corners {
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        Pixel(Point::new(0, 0), BinaryColor::On).draw(display)?;
+        Pixel(Point::new(WIDTH as i32 - 1, 1), BinaryColor::On).draw(display)
+    }
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::{rotating, Canvas, Corners, HEIGHT, WIDTH};
+use embassy_futures::join::join3;
+use embassy_futures::select::{select, Either};
+use embassy_time::{with_timeout, Duration};
+use embedded_graphics::geometry::{Point, Size};
+use pagrs_core::{capture_size, CaptureError, PageRotator};
+
+#[test]
+fn queries_of_several_tasks() {
+    let _rotating = rotating();
+    let mut pages = [Corners, Corners, Corners];
+    let mut rotator = PageRotator::<3, _>::new(Canvas::new());
+    // the pages are shown 1, 2 and 3 seconds long, to tell their answers apart
+    for (index, page) in pages.iter_mut().enumerate() {
+        rotator.add_page_with_duration(page, Duration::from_secs(index as u64 + 1)).ok().unwrap();
+    }
+    let controller = rotator.controller();
+
+    let script = async {
+        // tasks asking at the same time each get the answer to their own query
+        for _ in 0..3 {
+            let (status, page, stats) = join3(controller.status(), controller.page_status(2), controller.frame_stats(1)).await;
+            assert_eq!(status.page_count, 3);
+            assert_eq!(page.map(|page| page.duration), Some(Duration::from_secs(3)));
+            assert!(stats.is_some());
+        }
+        assert_eq!(controller.frame_stats(3).await, None);
+
+        // the answer to a query given up on isn't taken for the answer of the next one
+        let given_up = select(controller.page_status(0), core::future::ready(())).await;
+        assert!(matches!(given_up, Either::Second(())));
+        assert_eq!(controller.page_status(1).await.map(|page| page.duration), Some(Duration::from_secs(2)));
+    };
+
+    let result = embassy_futures::block_on(select(rotator.run(), with_timeout(Duration::from_secs(5), script)));
+    assert!(matches!(result, Either::Second(Ok(()))), "a query wasn't answered");
+}
+
+#[test]
+fn captures_given_up_on() {
+    let _rotating = rotating();
+    let mut page = Corners;
+    let mut rotator = PageRotator::<1, _>::new(Canvas::new());
+    rotator.add_page(&mut page).ok().unwrap();
+    let controller = rotator.controller();
+
+    let script = async {
+        // the rotator doesn't wait for a capture given up on, it keeps rendering and answering
+        let mut buffer = [0u8; capture_size(Size::new(WIDTH as u32, HEIGHT as u32))];
+        let given_up = select(controller.capture(&mut buffer), core::future::ready(())).await;
+        assert!(matches!(given_up, Either::Second(())));
+        // the next capture gets its own answer, even while the one given up on is still queued
+        let mut small = [0u8; 8];
+        assert_eq!(controller.capture(&mut small).await.err(), Some(CaptureError::BufferTooSmall));
+        assert_eq!(controller.status().await.page_count, 1);
+        assert!(buffer.iter().all(|byte| *byte == 0));
+
+        let frame = controller.capture(&mut buffer).await.unwrap();
+        assert_eq!(frame.pixel(Point::new(0, 0)), Some(true.into()));
+        assert_eq!(frame.pixel(Point::new(WIDTH as i32 - 1, 1)), Some(true.into()));
+        assert_eq!(frame.pixel(Point::new(1, 0)), Some(false.into()));
+    };
+
+    let result = embassy_futures::block_on(select(rotator.run(), with_timeout(Duration::from_secs(5), script)));
+    assert!(matches!(result, Either::Second(Ok(()))), "a query wasn't answered");
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::{rotating, Canvas, Corners, HEIGHT, WIDTH};
+use embassy_futures::select::{select3, Either3};
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::pipe::Pipe;
+use embedded_io_async::{Read, Write};
+use pagrs_core::{PageRotator, ParseError, RemoteCommand, RemoteControl};
+
+/// run the remote on a fixed input without a rotator, returning everything it answered
+fn answers(input: &[u8]) -> String {
+    let mut output = [0u8; 512];
+    let mut remote = RemoteControl::new(input, &mut output[..]);
+    embassy_futures::block_on(remote.run()).unwrap();
+    drop(remote);
+
+    String::from_utf8_lossy(&output).trim_end_matches('\0').to_string()
+}
+
+/// send a line through the pipe and read the answer up to the final `OK` or `ERR` line
+async fn send(input: &Pipe<NoopRawMutex, 64>, output: &Pipe<NoopRawMutex, 64>, line: &str) -> String {
+    let mut writer = input;
+    // the pipe's own `write_all` can't fail, the one of embedded-io-async returns a result
+    Write::write_all(&mut writer, line.as_bytes()).await.unwrap();
+    Write::write_all(&mut writer, b"\n").await.unwrap();
+
+    let mut reader = output;
+    let mut answer = String::new();
+    loop {
+        let mut chunk = [0u8; 64];
+        let length = Read::read(&mut reader, &mut chunk).await.unwrap();
+        answer.push_str(core::str::from_utf8(&chunk[..length]).unwrap());
+
+        let last = answer.trim_end_matches("\r\n").rsplit("\r\n").next().unwrap_or_default();
+        if answer.ends_with("\r\n") && (last == "OK" || last.starts_with("ERR ")) {
+            return answer;
+        }
+    }
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(RemoteCommand::parse(""), Err(ParseError::Empty));
+    assert_eq!(RemoteCommand::parse("jump"), Err(ParseError::MissingArgument));
+    assert_eq!(RemoteCommand::parse("jump x"), Err(ParseError::InvalidNumber));
+    assert_eq!(RemoteCommand::parse("duration 1"), Err(ParseError::MissingArgument));
+    assert_eq!(RemoteCommand::parse("duration 1 -5"), Err(ParseError::InvalidNumber));
+    assert_eq!(RemoteCommand::parse("next 1"), Err(ParseError::TooManyArguments));
+    assert_eq!(RemoteCommand::parse("foo"), Err(ParseError::UnknownCommand));
+
+    assert_eq!(
+        answers(b"jump\r\nbrightness 256\n\nnext 1\nfoo\n"),
+        "ERR missing argument\r\nERR invalid number\r\nERR too many arguments\r\nERR unknown command, try 'help'\r\n"
+    );
+}
+
+#[test]
+fn line_overflow() {
+    let mut input = vec![b'x'; 100];
+    input.extend_from_slice(b"\n\xff\nhelp\n");
+
+    let answer = answers(&input);
+    let mut lines = answer.lines();
+    assert_eq!(lines.next(), Some("ERR line too long"));
+    assert_eq!(lines.next(), Some("ERR invalid characters"));
+    // the lines after are read as usual
+    assert_eq!(lines.last(), Some("OK"));
+}
+
+#[test]
+fn drive_the_rotator() {
+    let _rotating = rotating();
+    let (mut first, mut second, mut third) = (Corners, Corners, Corners);
+    let mut rotator = PageRotator::<3, _>::new(Canvas::new());
+    rotator.add_page(&mut first).ok().unwrap();
+    rotator.add_page(&mut second).ok().unwrap();
+    rotator.add_page(&mut third).ok().unwrap();
+
+    let input = Pipe::<NoopRawMutex, 64>::new();
+    let output = Pipe::<NoopRawMutex, 64>::new();
+    let mut remote = RemoteControl::new(&input, &output);
+
+    let script = async {
+        assert_eq!(send(&input, &output, "jump 3").await, "ERR no such page\r\n");
+        assert_eq!(send(&input, &output, "jump 2").await, "OK\r\n");
+        assert_eq!(send(&input, &output, "pause").await, "OK\r\n");
+        assert_eq!(send(&input, &output, "status").await, "page 2/3 paused\r\nOK\r\n");
+
+        assert_eq!(send(&input, &output, "duration 1 0").await, "ERR duration must not be 0\r\n");
+        assert_eq!(send(&input, &output, "duration 3 1500").await, "ERR no such page\r\n");
+        assert_eq!(send(&input, &output, "duration 1 1500").await, "OK\r\n");
+        let list = send(&input, &output, "list").await;
+        assert_eq!(list.lines().nth(1), Some("1 1500ms enabled"));
+
+        let capture = send(&input, &output, "capture").await;
+        let mut lines = capture.lines();
+        assert_eq!(lines.next(), Some("P1"));
+        assert_eq!(lines.next(), Some("200 4"));
+        let rows: Vec<&str> = lines.by_ref().take(HEIGHT).collect();
+        assert!(rows.iter().all(|row| row.len() == WIDTH));
+        assert_eq!(rows[0].find('0'), Some(0));
+        assert_eq!(rows[1].find('0'), Some(WIDTH - 1));
+        assert!(!rows[2].contains('0') && !rows[3].contains('0'));
+        assert_eq!(lines.next(), Some("OK"));
+    };
+
+    let result = embassy_futures::block_on(select3(rotator.run(), remote.run(), script));
+    assert!(matches!(result, Either3::Third(())));
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use common::{rotating, Corners};
+use display_interface::{AsyncWriteOnlyDataCommand, DataFormat, DisplayError};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embassy_futures::select::{select, Either};
+use embassy_time::{with_timeout, Duration};
+use pagrs_core::{capture_size, Backend, MirrorBackend, Orientation, PageRotator, Scroll, ScrollDirection, ScrollSpeed, ScrollingSsd1306};
+use ssd1306::rotation::DisplayRotation;
+use ssd1306::size::DisplaySize128x64;
+
+/// an interface recording the bytes sent to the display
+#[derive(Default)]
+struct Recorder {
+    commands: Vec<u8>,
+    data: Vec<Vec<u8>>,
+}
+
+impl Recorder {
+    fn take(&mut self) -> (Vec<u8>, Vec<Vec<u8>>) {
+        (core::mem::take(&mut self.commands), core::mem::take(&mut self.data))
+    }
+}
+
+fn bytes(format: DataFormat<'_>) -> Vec<u8> {
+    match format {
+        DataFormat::U8(bytes) => bytes.to_vec(),
+        _ => panic!("only bytes are sent to the SSD1306"),
+    }
+}
+
+impl AsyncWriteOnlyDataCommand for Recorder {
+    async fn send_commands(&mut self, commands: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.commands.extend(bytes(commands));
+        Ok(())
+    }
+
+    async fn send_data(&mut self, data: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.data.push(bytes(data));
+        Ok(())
+    }
+}
+
+#[test]
+fn flush_the_changes() {
+    let mut display = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate0);
+    embassy_futures::block_on(async {
+        display.init().await.unwrap();
+        display.flush().await.unwrap();
+    });
+    let mut interface = display.release();
+    let (_, data) = interface.take();
+    assert_eq!(data.len(), 8);
+    assert!(data.iter().all(|page| page.len() == 128 && page.iter().all(|byte| *byte == 0)));
+
+    let mut display = ScrollingSsd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    Pixel(Point::new(5, 10), BinaryColor::On).draw(&mut display).unwrap();
+    Pixel(Point::new(7, 20), BinaryColor::On).draw(&mut display).unwrap();
+    assert_eq!(display.read_pixel(Point::new(7, 20)), Some(BinaryColor::On));
+    assert_eq!(display.read_pixel(Point::new(7, 21)), Some(BinaryColor::Off));
+    assert_eq!(display.read_pixel(Point::new(128, 0)), None);
+    embassy_futures::block_on(async {
+        display.flush().await.unwrap();
+        // nothing changed since
+        display.flush().await.unwrap();
+    });
+
+    let (commands, data) = display.release().take();
+    // the columns 5 to 7 of the pages 1 and 2
+    assert_eq!(commands, [0x21, 5, 7, 0x22, 1, 2]);
+    assert_eq!(data, [vec![0b100, 0, 0], vec![0, 0, 0b1_0000]]);
+}
+
+#[test]
+fn rotate_in_software() {
+    let mut display = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate90);
+    assert_eq!(display.size(), Size::new(64, 128));
+
+    Pixel(Point::new(0, 0), BinaryColor::On).draw(&mut display).unwrap();
+    assert_eq!(display.read_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+    assert_eq!(display.read_pixel(Point::new(63, 127)), Some(BinaryColor::Off));
+
+    embassy_futures::block_on(async {
+        display.set_orientation(Orientation::Rotate0).await.unwrap();
+    });
+    assert_eq!(display.size(), Size::new(128, 64));
+    assert_eq!(display.read_pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+}
+
+#[test]
+fn scroll_via_the_interface() {
+    let scroll = Scroll::new(6, 7, ScrollDirection::Left, ScrollSpeed::Frames4);
+    let mut display = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate0);
+    Pixel(Point::new(0, 0), BinaryColor::On).draw(&mut display).unwrap();
+    embassy_futures::block_on(async {
+        display.flush().await.unwrap();
+        assert!(display.start_scroll(&scroll).await.unwrap());
+    });
+
+    let mut interface = display.release();
+    interface.take();
+    let mut display = ScrollingSsd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0);
+    Pixel(Point::new(0, 0), BinaryColor::On).draw(&mut display).unwrap();
+    embassy_futures::block_on(async {
+        assert!(display.start_scroll(&scroll).await.unwrap());
+    });
+    // only the scroll commands, neither the setup of the display nor its frame
+    let (commands, data) = display.release().take();
+    assert_eq!(commands[0], 0x2E);
+    assert_eq!(commands[commands.len() - 1], 0x2F);
+    assert!(!commands.contains(&0xAE));
+    assert!(data.is_empty());
+
+    let mut display = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate90);
+    embassy_futures::block_on(async {
+        // the SSD1306 only scrolls the rows of its memory
+        assert!(!display.start_scroll(&scroll).await.unwrap());
+    });
+}
+
+#[test]
+fn capture_without_a_wrapper() {
+    let _rotating = rotating();
+    let mut page = Corners;
+    let display = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate90);
+    let mut rotator = PageRotator::<1, _>::new(display);
+    rotator.add_page(&mut page).ok().unwrap();
+    let controller = rotator.controller();
+
+    let script = async {
+        let mut buffer = [0u8; capture_size(Size::new(64, 128))];
+        let frame = controller.capture(&mut buffer).await.unwrap();
+        assert_eq!(frame.size(), Size::new(64, 128));
+        assert_eq!(frame.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(frame.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+    };
+
+    let result = embassy_futures::block_on(select(rotator.run(), with_timeout(Duration::from_secs(5), script)));
+    assert!(matches!(result, Either::Second(Ok(()))), "the capture wasn't answered");
+}
+
+#[test]
+fn mirror_portrait_panels() {
+    let left = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate90);
+    let right = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate270);
+    let mirror = MirrorBackend::<_, 2, 64, 16>::new([left, right]);
+    assert_eq!(mirror.size(), Size::new(64, 128));
+}
+
+#[test]
+#[should_panic(expected = "the displays of a MirrorBackend must have the size of its frame")]
+fn mirror_mixed_panels() {
+    let landscape = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate0);
+    let portrait = ScrollingSsd1306::new(Recorder::default(), DisplaySize128x64, DisplayRotation::Rotate90);
+    MirrorBackend::<_, 2, 128, 8>::new([landscape, portrait]);
+}
@@ -0,0 +1,262 @@
+mod common;
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use common::{rotating, Canvas, Corners};
+use embassy_futures::select::{select, Either};
+use embassy_time::{with_timeout, Duration, Timer};
+use embedded_storage::nor_flash::{ErrorType, NorFlash, NorFlashErrorKind, ReadNorFlash};
+use pagrs_core::{MemoryStorage, NorFlashStorage, PageRotator, StateStorage, StorageError};
+
+const SECTOR_SIZE: usize = 128;
+
+/// how a write to the [RamFlash] fails
+#[derive(Clone, Copy)]
+enum Failure {
+    /// before touching the flash
+    Untouched,
+    /// after the given amount of bytes, e.g. on a power loss
+    After(usize),
+}
+
+/// two sectors of four records of NOR flash in RAM: erasing sets all bits, writing only clears them
+struct RamFlash {
+    data: [u8; 2 * SECTOR_SIZE],
+    erases: usize,
+    /// the next write fails this way
+    failure: Option<Failure>,
+}
+
+impl RamFlash {
+    fn new() -> Self {
+        Self {
+            data: [0; 2 * SECTOR_SIZE],
+            erases: 0,
+            failure: None,
+        }
+    }
+}
+
+impl ErrorType for RamFlash {
+    type Error = NorFlashErrorKind;
+}
+
+impl ReadNorFlash for RamFlash {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = offset as usize;
+        bytes.copy_from_slice(&self.data[offset..offset + bytes.len()]);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl NorFlash for RamFlash {
+    const WRITE_SIZE: usize = 4;
+    const ERASE_SIZE: usize = SECTOR_SIZE;
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        self.data[from as usize..to as usize].fill(0xFF);
+        self.erases += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let written = match self.failure.take() {
+            Some(Failure::Untouched) => 0,
+            Some(Failure::After(length)) => length,
+            None => bytes.len(),
+        };
+        let offset = offset as usize;
+        for (cell, byte) in self.data[offset..].iter_mut().zip(&bytes[..written]) {
+            *cell &= byte;
+        }
+
+        if written < bytes.len() {
+            return Err(NorFlashErrorKind::Other);
+        }
+        Ok(())
+    }
+}
+
+/// the data saved last, as read after a restart
+fn reload(flash: RamFlash) -> (Vec<u8>, RamFlash) {
+    let mut storage = NorFlashStorage::new(flash, 0);
+    let mut buffer = [0; 27];
+    let length = storage.load(&mut buffer).unwrap();
+    (buffer[..length].to_vec(), storage.release())
+}
+
+/// save `data` after a restart
+fn save(flash: RamFlash, data: &[u8]) -> (Result<(), StorageError>, RamFlash) {
+    let mut storage = NorFlashStorage::new(flash, 0);
+    let result = storage.save(data);
+    (result, storage.release())
+}
+
+#[test]
+fn switch_sectors() {
+    let mut storage = NorFlashStorage::new(RamFlash::new(), 0);
+    let mut buffer = [0; 27];
+    assert_eq!(storage.load(&mut buffer), Ok(0));
+
+    let mut flash = storage.release();
+    for value in 1..=10u8 {
+        let result;
+        (result, flash) = save(flash, &[value; 3]);
+        assert_eq!(result, Ok(()));
+
+        let data;
+        (data, flash) = reload(flash);
+        assert_eq!(data, [value; 3]);
+    }
+    // each sector is erased when starting it over: the first on the 1st and 9th record, the second on the 5th
+    assert_eq!(flash.erases, 3);
+
+    let mut storage = NorFlashStorage::new(flash, 0);
+    assert_eq!(storage.save(&[0; 28]), Err(StorageError::TooLarge));
+}
+
+#[test]
+fn skip_partial_records() {
+    let mut storage = NorFlashStorage::new(RamFlash::new(), 0);
+    storage.save(b"first").unwrap();
+    let mut flash = storage.release();
+
+    // power lost in the middle of the record
+    flash.failure = Some(Failure::After(8));
+    let (result, flash) = save(flash, b"second");
+    assert_eq!(result, Err(StorageError::Memory));
+    let (data, flash) = reload(flash);
+    assert_eq!(data, b"first");
+
+    // the partial record keeps its slot, the next one goes after it
+    let (result, flash) = save(flash, b"third");
+    assert_eq!(result, Ok(()));
+    let (data, _) = reload(flash);
+    assert_eq!(data, b"third");
+}
+
+#[test]
+fn skip_failed_writes() {
+    let (result, mut flash) = save(RamFlash::new(), b"first");
+    assert_eq!(result, Ok(()));
+
+    // the write fails before touching the flash, the slot stays erased in between two records
+    flash.failure = Some(Failure::Untouched);
+    let mut storage = NorFlashStorage::new(flash, 0);
+    assert_eq!(storage.save(b"lost"), Err(StorageError::Memory));
+    assert_eq!(storage.save(b"second"), Ok(()));
+
+    let (data, flash) = reload(storage.release());
+    assert_eq!(data, b"second");
+    let (result, flash) = save(flash, b"third");
+    assert_eq!(result, Ok(()));
+    let (data, _) = reload(flash);
+    assert_eq!(data, b"third");
+}
+
+#[test]
+fn wrap_the_generation() {
+    // each sector holds four records, so the generation overflows after 2^16 sectors of them
+    let mut storage = NorFlashStorage::new(RamFlash::new(), 0);
+    let saves = 4 * (u16::MAX as u32 + 1);
+    for value in 0..saves - 6 {
+        storage.save(&value.to_le_bytes()).unwrap();
+    }
+
+    let mut flash = storage.release();
+    for value in saves - 6..saves + 6 {
+        let result;
+        (result, flash) = save(flash, &value.to_le_bytes());
+        assert_eq!(result, Ok(()));
+
+        let data;
+        (data, flash) = reload(flash);
+        assert_eq!(data, value.to_le_bytes(), "after save {}", value);
+    }
+}
+
+/// how often the [Counting] storage saved
+static SAVES: AtomicUsize = AtomicUsize::new(0);
+
+/// a storage counting the saves
+struct Counting<'a>(&'a mut MemoryStorage<8>);
+
+impl StateStorage for Counting<'_> {
+    fn load(&mut self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        self.0.load(buffer)
+    }
+
+    fn save(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        SAVES.fetch_add(1, Ordering::Relaxed);
+        self.0.save(data)
+    }
+}
+
+#[test]
+fn restore_on_init() {
+    let _rotating = rotating();
+    let mut memory = MemoryStorage::<8>::new();
+
+    {
+        let mut pages = [Corners, Corners, Corners];
+        let mut rotator = PageRotator::<3, _>::with_storage(Canvas::new(), &mut memory);
+        for page in pages.iter_mut() {
+            rotator.add_page(page).ok().unwrap();
+        }
+        let controller = rotator.controller();
+        let script = async {
+            controller.jump_to(2).await;
+            controller.pause().await;
+            controller.set_brightness(42).await;
+            controller.disable_page(1).await;
+            // answered once the commands before are applied
+            controller.status().await
+        };
+        let result = embassy_futures::block_on(select(rotator.run(), with_timeout(Duration::from_secs(5), script)));
+        assert!(matches!(result, Either::Second(Ok(_))), "the rotator didn't answer");
+    }
+
+    let mut pages = [Corners, Corners, Corners];
+    let mut rotator = PageRotator::<3, _>::with_storage(Canvas::new(), &mut memory);
+    for page in pages.iter_mut() {
+        rotator.add_page(page).ok().unwrap();
+    }
+    let controller = rotator.controller();
+    let script = async {
+        let status = controller.status().await;
+        assert_eq!(status.current_page, 2);
+        assert!(status.paused);
+        assert_eq!(status.brightness, Some(42));
+        assert_eq!(controller.page_status(1).await.map(|page| page.enabled), Some(false));
+    };
+    let rotation = async {
+        rotator.init().await.unwrap();
+        rotator.run().await
+    };
+    let result = embassy_futures::block_on(select(rotation, with_timeout(Duration::from_secs(5), script)));
+    assert!(matches!(result, Either::Second(Ok(()))), "the rotator didn't answer");
+}
+
+#[test]
+fn save_the_rotation_at_most_once_per_interval() {
+    let _rotating = rotating();
+    let mut memory = MemoryStorage::<8>::new();
+    let mut storage = Counting(&mut memory);
+    let mut pages = [Corners, Corners, Corners];
+    let mut rotator = PageRotator::<3, _>::with_storage(Canvas::new(), &mut storage);
+    for page in pages.iter_mut() {
+        rotator.add_page_with_duration(page, Duration::from_millis(20)).ok().unwrap();
+    }
+    rotator.save_rotation_every(Duration::from_millis(300));
+
+    // about 50 pages are rotated to, only about every 15th of them is saved
+    let result = embassy_futures::block_on(select(rotator.run(), Timer::after(Duration::from_secs(1))));
+    assert!(matches!(result, Either::Second(())));
+    let saves = SAVES.load(Ordering::Relaxed);
+    assert!((2..=4).contains(&saves), "saved {} times", saves);
+}
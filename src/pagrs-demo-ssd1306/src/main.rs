@@ -12,7 +12,7 @@ use heapless::String;
 use rand::prelude::SmallRng;
 use rand::{Rng, SeedableRng};
 use ssd1306::prelude::*;
-use ssd1306::{I2CDisplayInterface, Ssd1306Async};
+use ssd1306::I2CDisplayInterface;
 use ufmt::uwrite;
 
 #[allow(unused_imports)]
@@ -22,7 +22,7 @@ use pagrs::DigitalRain;
 use pagrs::PageRotator;
 use pagrs::{DynamicText, StaticText};
 use pagrs::{HorizontalAlignment, StaticImage, VerticalAlignment};
-use pagrs::{PageController, Screensaver};
+use pagrs::{PageController, Screensaver, ScrollingSsd1306};
 
 bind_interrupts!(struct I2cIrqs {
     I2C1_IRQ => embassy_rp::i2c::InterruptHandler<I2C1>;
@@ -82,8 +82,8 @@ async fn rotate(sda: embassy_rp::peripherals::PIN_6, scl: embassy_rp::peripheral
     config.frequency = 400_000;
     let bus = embassy_rp::i2c::I2c::new_async(i2c_channel, scl, sda, I2cIrqs, config);
     let interface = I2CDisplayInterface::new(bus);
-    let display = Ssd1306Async::new(interface, DisplaySize128x64, DisplayRotation::Rotate180)
-        .into_buffered_graphics_mode();
+    // unlike a plain `Ssd1306Async` it can read back its frame, to scroll and capture the pages
+    let display = ScrollingSsd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate180);
 
     // create pages (need to be created before pagr
     // as resources are dropped in inverse order
@@ -108,7 +108,7 @@ async fn rotate(sda: embassy_rp::peripherals::PIN_6, scl: embassy_rp::peripheral
     let mut matrix_rain = DigitalRain::<16, 7, 16>::new(0xDA7A);
 
     // create pagr object
-    let mut pagr = PageRotator::<5, _, _, _>::new(display);
+    let mut pagr = PageRotator::<5, _>::new(display);
     pagr.init().await.unwrap();
     let _ = pagr.add_page(&mut text);
     let _ = pagr.add_page(&mut screensaver);
@@ -116,5 +116,5 @@ async fn rotate(sda: embassy_rp::peripherals::PIN_6, scl: embassy_rp::peripheral
     let _ = pagr.add_page_with_duration(&mut static_bmp, Duration::from_secs(1));
     let _ = pagr.add_page_with_duration(&mut matrix_rain, Duration::from_secs(10));
 
-    pagr.rotate(spawner).await
+    pagr.run().await
 }
@@ -0,0 +1,48 @@
+use pagrs_core::{InputEvent, PageController};
+use pagrs_core::fmt::debug;
+
+/// a command sent to the [PageRotator](pagrs_core::PageRotator) when a gesture is detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Action {
+    /// cycle to the next page
+    Next,
+    /// cycle to the previous page
+    Previous,
+    /// keep showing the current page
+    Pause,
+    /// continue the rotation
+    Resume,
+    /// pause if rotating, resume if paused
+    TogglePause,
+    /// show the page with the given index
+    JumpTo(usize),
+    /// change the brightness of the display
+    SetBrightness(u8),
+    /// send an input event to the page currently shown, which may use it e.g. to move a cursor.
+    /// If the page doesn't consume it, the event is used for navigation.
+    Input(InputEvent),
+}
+
+impl Action {
+    /// send the action via the `controller`
+    pub async fn perform(self, controller: &PageController) {
+        debug!("performing {:?}", self);
+        match self {
+            Action::Next => controller.next().await,
+            Action::Previous => controller.previous().await,
+            Action::Pause => controller.pause().await,
+            Action::Resume => controller.resume().await,
+            Action::TogglePause => {
+                if controller.status().await.paused {
+                    controller.resume().await;
+                } else {
+                    controller.pause().await;
+                }
+            }
+            Action::JumpTo(index) => controller.jump_to(index).await,
+            Action::SetBrightness(brightness) => controller.set_brightness(brightness).await,
+            Action::Input(event) => controller.input(event).await,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use embassy_futures::select::{select, Either};
+use embassy_time::{Duration, Instant, Timer};
+use embedded_hal::digital::InputPin;
+use embedded_hal_async::digital::Wait;
+use pagrs_core::PageController;
+use pagrs_core::fmt::trace;
+use crate::action::Action;
+
+/// a gesture performed with a [Button]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Gesture {
+    /// pressed and released within the long press time
+    ShortPress,
+    /// held for at least the long press time. Detected while the button is still held.
+    LongPress,
+    /// two short presses within the double press time
+    DoublePress,
+}
+
+/// timings and wiring of a [Button]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ButtonConfig {
+    /// how long the level must be stable after an edge to count, filtering out the bouncing of the contacts
+    pub debounce: Duration,
+    /// how long the button must be held for a [Gesture::LongPress]
+    pub long_press: Duration,
+    /// how long after releasing the button a second press makes a [Gesture::DoublePress]
+    pub double_press: Duration,
+    /// if the pin reads low while the button is pressed, e.g. a button to ground with a pull-up
+    pub active_low: bool,
+}
+
+impl Default for ButtonConfig {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(20),
+            long_press: Duration::from_millis(800),
+            double_press: Duration::from_millis(300),
+            active_low: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DetectorState {
+    Idle,
+    Pressed { since: Instant },
+    LongHeld,
+    WaitingForSecondPress { released: Instant },
+    SecondPress,
+}
+
+/// turn the debounced presses and releases of a button into [gestures](Gesture).
+///
+/// The detector does not wait on its own: whenever a [`deadline`](GestureDetector::deadline) is set, the caller has to
+/// call [`on_timeout`](GestureDetector::on_timeout) once it passed. The [Button] does so for a pin.
+///
+/// ## example
+/// ```rust
+/// use embassy_time::Instant;
+/// use pagrs_input::{ButtonConfig, Gesture, GestureDetector};
+///
+/// let mut detector = GestureDetector::new(ButtonConfig::default(), true);
+/// let at = Instant::from_millis;
+///
+/// // two quick presses
+/// assert_eq!(detector.on_press(at(0)), None);
+/// assert_eq!(detector.on_release(at(100)), None);
+/// assert_eq!(detector.on_press(at(250)), None);
+/// assert_eq!(detector.on_release(at(350)), Some(Gesture::DoublePress));
+///
+/// // a single press is reported once the double press time passed
+/// detector.on_press(at(1000));
+/// detector.on_release(at(1100));
+/// assert_eq!(detector.deadline(), Some(at(1400)));
+/// assert_eq!(detector.on_timeout(at(1400)), Some(Gesture::ShortPress));
+///
+/// // a long press is reported while still holding the button
+/// detector.on_press(at(2000));
+/// assert_eq!(detector.on_timeout(at(2800)), Some(Gesture::LongPress));
+/// assert_eq!(detector.on_release(at(3000)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GestureDetector {
+    config: ButtonConfig,
+    double_press_enabled: bool,
+    state: DetectorState,
+}
+
+impl GestureDetector {
+    /// create a new [GestureDetector]. If `double_press_enabled` is `false` a short press is reported right on release
+    /// instead of waiting for a possible second press.
+    pub fn new(config: ButtonConfig, double_press_enabled: bool) -> Self {
+        Self {
+            config,
+            double_press_enabled,
+            state: DetectorState::Idle,
+        }
+    }
+
+    /// when [`on_timeout`](GestureDetector::on_timeout) has to be called, `None` if waiting for the next press or release
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.state {
+            DetectorState::Pressed { since } => Some(since + self.config.long_press),
+            DetectorState::WaitingForSecondPress { released } => Some(released + self.config.double_press),
+            _ => None,
+        }
+    }
+
+    /// the button got pressed at `at`
+    pub fn on_press(&mut self, at: Instant) -> Option<Gesture> {
+        self.state = match self.state {
+            DetectorState::WaitingForSecondPress { .. } => DetectorState::SecondPress,
+            _ => DetectorState::Pressed { since: at },
+        };
+
+        None
+    }
+
+    /// the button got released at `at`
+    pub fn on_release(&mut self, at: Instant) -> Option<Gesture> {
+        match self.state {
+            DetectorState::Pressed { .. } if self.double_press_enabled => {
+                self.state = DetectorState::WaitingForSecondPress { released: at };
+                None
+            }
+            DetectorState::Pressed { .. } => {
+                self.state = DetectorState::Idle;
+                Some(Gesture::ShortPress)
+            }
+            DetectorState::SecondPress => {
+                self.state = DetectorState::Idle;
+                Some(Gesture::DoublePress)
+            }
+            _ => {
+                self.state = DetectorState::Idle;
+                None
+            }
+        }
+    }
+
+    /// check if the button was held long enough or no second press followed, as of `now`
+    pub fn on_timeout(&mut self, now: Instant) -> Option<Gesture> {
+        if self.deadline().is_none_or(|deadline| now < deadline) {
+            return None;
+        }
+
+        match self.state {
+            DetectorState::Pressed { .. } => {
+                self.state = DetectorState::LongHeld;
+                Some(Gesture::LongPress)
+            }
+            DetectorState::WaitingForSecondPress { .. } => {
+                self.state = DetectorState::Idle;
+                Some(Gesture::ShortPress)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// a debounced push button on an input pin, detecting [gestures](Gesture).
+///
+/// ## type parameters
+/// - `P`: the input pin, able to wait for edges (e.g. `embassy_rp::gpio::Input`)
+pub struct Button<P> {
+    pin: P,
+    config: ButtonConfig,
+    detector: GestureDetector,
+    pressed: bool,
+}
+
+impl<P: InputPin + Wait> Button<P> {
+    /// create a new [Button]. The button is expected to be released at the start.
+    pub fn new(pin: P, config: ButtonConfig) -> Self {
+        Self {
+            pin,
+            config,
+            detector: GestureDetector::new(config, true),
+            pressed: false,
+        }
+    }
+
+    /// report a short press right on release, instead of waiting whether a [Gesture::DoublePress] follows
+    pub fn without_double_press(mut self) -> Self {
+        self.detector = GestureDetector::new(self.config, false);
+        self
+    }
+
+    /// wait for the next gesture
+    pub async fn next_gesture(&mut self) -> Result<Gesture, P::Error> {
+        loop {
+            let edge = match self.detector.deadline() {
+                Some(deadline) => match select(self.pin.wait_for_any_edge(), Timer::at(deadline)).await {
+                    Either::First(edge) => Some(edge),
+                    Either::Second(_) => None,
+                },
+                None => Some(self.pin.wait_for_any_edge().await),
+            };
+
+            let gesture = match edge {
+                Some(edge) => {
+                    edge?;
+                    self.debounced_edge().await?
+                }
+                None => self.detector.on_timeout(Instant::now()),
+            };
+
+            if let Some(gesture) = gesture {
+                return Ok(gesture);
+            }
+        }
+    }
+
+    /// wait for the level to settle and pass a change on to the detector
+    async fn debounced_edge(&mut self) -> Result<Option<Gesture>, P::Error> {
+        let at = Instant::now();
+        Timer::after(self.config.debounce).await;
+
+        let pressed = self.pin.is_low()? == self.config.active_low;
+        if pressed == self.pressed {
+            return Ok(None);
+        }
+
+        self.pressed = pressed;
+        Ok(if pressed {
+            self.detector.on_press(at)
+        } else {
+            self.detector.on_release(at)
+        })
+    }
+}
+
+/// which [Action] to perform for which [Gesture] of a [ButtonInput]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ButtonMapping {
+    pub short_press: Option<Action>,
+    pub long_press: Option<Action>,
+    pub double_press: Option<Action>,
+}
+
+impl ButtonMapping {
+    fn action(&self, gesture: Gesture) -> Option<Action> {
+        match gesture {
+            Gesture::ShortPress => self.short_press,
+            Gesture::LongPress => self.long_press,
+            Gesture::DoublePress => self.double_press,
+        }
+    }
+}
+
+impl Default for ButtonMapping {
+    /// short press: next page, double press: previous page, long press: pause or resume
+    fn default() -> Self {
+        Self {
+            short_press: Some(Action::Next),
+            long_press: Some(Action::TogglePause),
+            double_press: Some(Action::Previous),
+        }
+    }
+}
+
+/// control the [PageRotator](pagrs_core::PageRotator) with a single button.
+///
+/// ## example
+/// ```rust,ignore
+/// #[embassy_executor::task]
+/// async fn button(pin: embassy_rp::peripherals::PIN_15) {
+///     let button = Button::new(Input::new(pin, Pull::Up), ButtonConfig::default());
+///     let mut input = ButtonInput::new(button, ButtonMapping::default());
+///     let _ = input.run().await;
+/// }
+/// ```
+pub struct ButtonInput<P> {
+    button: Button<P>,
+    mapping: ButtonMapping,
+    controller: PageController,
+}
+
+impl<P: InputPin + Wait> ButtonInput<P> {
+    /// create a new [ButtonInput]. Without an action for [Gesture::DoublePress] short presses are reported right away.
+    pub fn new(button: Button<P>, mapping: ButtonMapping) -> Self {
+        Self {
+            button: if mapping.double_press.is_some() { button } else { button.without_double_press() },
+            mapping,
+            controller: PageController::new(),
+        }
+    }
+
+    /// perform the mapped actions for all gestures, until reading the pin fails
+    pub async fn run(&mut self) -> Result<(), P::Error> {
+        loop {
+            let gesture = self.button.next_gesture().await?;
+            trace!("button gesture {:?}", gesture);
+            if let Some(action) = self.mapping.action(gesture) {
+                action.perform(&self.controller).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use embassy_futures::select::{select, Either};
+use embedded_hal::digital::InputPin;
+use embedded_hal_async::digital::Wait;
+use pagrs_core::PageController;
+use pagrs_core::fmt::trace;
+use crate::action::Action;
+
+/// the change of the count for each transition from one state of the pins to the next, indexed by `previous << 2 | current`.
+/// The states follow the gray code `00 -> 10 -> 11 -> 01` when turning clockwise, invalid transitions count as `0`.
+const TRANSITIONS: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
+
+/// the direction of a step of a rotary [Encoder]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// turn the levels of the two pins of a quadrature encoder into steps.
+///
+/// ## example
+/// ```rust
+/// use pagrs_input::{Direction, QuadratureDecoder};
+///
+/// let mut decoder = QuadratureDecoder::new(4);
+/// decoder.update(false, false);
+///
+/// // a full detent clockwise
+/// assert_eq!(decoder.update(true, false), None);
+/// assert_eq!(decoder.update(true, true), None);
+/// assert_eq!(decoder.update(false, true), None);
+/// assert_eq!(decoder.update(false, false), Some(Direction::Clockwise));
+///
+/// // bouncing back and forth doesn't count
+/// assert_eq!(decoder.update(false, true), None);
+/// assert_eq!(decoder.update(false, false), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuadratureDecoder {
+    steps_per_detent: i8,
+    state: Option<u8>,
+    count: i8,
+}
+
+impl QuadratureDecoder {
+    /// create a new [QuadratureDecoder], reporting a step every `steps_per_detent` transitions (typically 4, 2 or 1)
+    pub fn new(steps_per_detent: u8) -> Self {
+        Self {
+            steps_per_detent: steps_per_detent.clamp(1, 4) as i8,
+            state: None,
+            count: 0,
+        }
+    }
+
+    /// pass the current level of the pins, returning a step once a detent is complete
+    pub fn update(&mut self, a: bool, b: bool) -> Option<Direction> {
+        let current = ((a as u8) << 1) | b as u8;
+        let previous = self.state.replace(current)?;
+
+        self.count += TRANSITIONS[((previous << 2) | current) as usize];
+        if self.count >= self.steps_per_detent {
+            self.count = 0;
+            Some(Direction::Clockwise)
+        } else if self.count <= -self.steps_per_detent {
+            self.count = 0;
+            Some(Direction::CounterClockwise)
+        } else {
+            None
+        }
+    }
+}
+
+/// a rotary encoder with two quadrature pins, detecting the steps it is turned.
+///
+/// ## type parameters
+/// - `A`, `B`: the input pins, able to wait for edges (e.g. `embassy_rp::gpio::Input`)
+///
+/// ## example
+/// pins toggled by a test, turning the encoder one detent clockwise:
+/// ```rust
+/// use core::cell::Cell;
+/// use core::convert::Infallible;
+/// use embedded_hal::digital::{ErrorType, InputPin};
+/// use embedded_hal_async::digital::Wait;
+/// use pagrs_input::{Direction, Encoder};
+///
+/// const STATES: [(bool, bool); 5] = [(false, false), (true, false), (true, true), (false, true), (false, false)];
+///
+/// // both pins share the position in the list of states, each edge moves one further
+/// struct MockPin<'s> { step: &'s Cell<usize>, is_a: bool }
+/// impl ErrorType for MockPin<'_> { type Error = Infallible; }
+/// impl InputPin for MockPin<'_> {
+///     fn is_high(&mut self) -> Result<bool, Infallible> {
+///         let (a, b) = STATES[self.step.get().min(STATES.len() - 1)];
+///         Ok(if self.is_a { a } else { b })
+///     }
+///     fn is_low(&mut self) -> Result<bool, Infallible> { self.is_high().map(|high| !high) }
+/// }
+/// impl Wait for MockPin<'_> {
+///     async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+///         while self.is_low()? { self.wait_for_any_edge().await?; }
+///         Ok(())
+///     }
+///     async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+///         while self.is_high()? { self.wait_for_any_edge().await?; }
+///         Ok(())
+///     }
+///     async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+///         self.wait_for_low().await?;
+///         self.wait_for_high().await
+///     }
+///     async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+///         self.wait_for_high().await?;
+///         self.wait_for_low().await
+///     }
+///     async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+///         if !self.is_a { core::future::pending::<()>().await; }
+///         self.step.set(self.step.get() + 1);
+///         Ok(())
+///     }
+/// }
+///
+/// let step = Cell::new(0);
+/// let mut encoder = Encoder::new(MockPin { step: &step, is_a: true }, MockPin { step: &step, is_a: false }, 4);
+/// assert_eq!(embassy_futures::block_on(encoder.next_step()), Ok(Direction::Clockwise));
+/// ```
+pub struct Encoder<A, B> {
+    a: A,
+    b: B,
+    decoder: QuadratureDecoder,
+}
+
+impl<A, B> Encoder<A, B>
+where
+    A: InputPin + Wait,
+    B: InputPin<Error = A::Error> + Wait,
+{
+    /// create a new [Encoder], reporting a step every `steps_per_detent` transitions of the pins
+    pub fn new(a: A, b: B, steps_per_detent: u8) -> Self {
+        Self {
+            a,
+            b,
+            decoder: QuadratureDecoder::new(steps_per_detent),
+        }
+    }
+
+    /// wait for the next step the encoder is turned
+    pub async fn next_step(&mut self) -> Result<Direction, A::Error> {
+        // the levels before the first edge are the starting point
+        self.decoder.update(self.a.is_high()?, self.b.is_high()?);
+
+        loop {
+            match select(self.a.wait_for_any_edge(), self.b.wait_for_any_edge()).await {
+                Either::First(edge) | Either::Second(edge) => edge?,
+            }
+
+            if let Some(direction) = self.decoder.update(self.a.is_high()?, self.b.is_high()?) {
+                return Ok(direction);
+            }
+        }
+    }
+}
+
+/// which [Action] to perform for which [Direction] of an [EncoderInput]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncoderMapping {
+    pub clockwise: Option<Action>,
+    pub counter_clockwise: Option<Action>,
+}
+
+impl Default for EncoderMapping {
+    /// clockwise: next page, counter-clockwise: previous page
+    fn default() -> Self {
+        Self {
+            clockwise: Some(Action::Next),
+            counter_clockwise: Some(Action::Previous),
+        }
+    }
+}
+
+/// control the [PageRotator](pagrs_core::PageRotator) with a rotary encoder.
+///
+/// ## example
+/// ```rust,ignore
+/// #[embassy_executor::task]
+/// async fn encoder(a: embassy_rp::peripherals::PIN_16, b: embassy_rp::peripherals::PIN_17) {
+///     let encoder = Encoder::new(Input::new(a, Pull::Up), Input::new(b, Pull::Up), 4);
+///     let mut input = EncoderInput::new(encoder, EncoderMapping::default());
+///     let _ = input.run().await;
+/// }
+/// ```
+pub struct EncoderInput<A, B> {
+    encoder: Encoder<A, B>,
+    mapping: EncoderMapping,
+    controller: PageController,
+}
+
+impl<A, B> EncoderInput<A, B>
+where
+    A: InputPin + Wait,
+    B: InputPin<Error = A::Error> + Wait,
+{
+    /// create a new [EncoderInput]
+    pub fn new(encoder: Encoder<A, B>, mapping: EncoderMapping) -> Self {
+        Self {
+            encoder,
+            mapping,
+            controller: PageController::new(),
+        }
+    }
+
+    /// perform the mapped actions for all steps, until reading the pins fails
+    pub async fn run(&mut self) -> Result<(), A::Error> {
+        loop {
+            let direction = self.encoder.next_step().await?;
+            trace!("encoder step {:?}", direction);
+            let action = match direction {
+                Direction::Clockwise => self.mapping.clockwise,
+                Direction::CounterClockwise => self.mapping.counter_clockwise,
+            };
+
+            if let Some(action) = action {
+                action.perform(&self.controller).await;
+            }
+        }
+    }
+}
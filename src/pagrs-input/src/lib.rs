@@ -0,0 +1,9 @@
+#![no_std]
+
+mod action;
+mod button;
+mod encoder;
+
+pub use action::Action;
+pub use button::{Button, ButtonConfig, ButtonInput, ButtonMapping, Gesture, GestureDetector};
+pub use encoder::{Direction, Encoder, EncoderInput, EncoderMapping, QuadratureDecoder};
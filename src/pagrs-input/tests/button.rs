@@ -0,0 +1,73 @@
+mod common;
+
+use common::ScriptedPin;
+use embassy_time::{with_timeout, Duration, Instant};
+use pagrs_input::{Button, ButtonConfig, Gesture};
+
+const CONFIG: ButtonConfig = ButtonConfig {
+    debounce: Duration::from_millis(20),
+    long_press: Duration::from_millis(300),
+    double_press: Duration::from_millis(150),
+    active_low: true,
+};
+
+/// a button to ground with a pull-up, pressed whenever the script toggles the pin low
+fn button(toggles: &'static [u64]) -> (Button<ScriptedPin>, Instant) {
+    let start = Instant::now();
+    (Button::new(ScriptedPin::new(start, true, toggles), CONFIG), start)
+}
+
+/// the next gesture, `None` if there is none within a second
+fn next(button: &mut Button<ScriptedPin>) -> Option<Gesture> {
+    embassy_futures::block_on(with_timeout(Duration::from_secs(1), button.next_gesture()))
+        .ok()
+        .map(Result::unwrap)
+}
+
+#[test]
+fn bouncing_contacts_count_once() {
+    let (button, _) = button(&[20, 23, 26, 100, 103, 106]);
+    let mut button = button.without_double_press();
+
+    assert_eq!(next(&mut button), Some(Gesture::ShortPress));
+    assert_eq!(next(&mut button), None);
+}
+
+#[test]
+fn glitches_shorter_than_the_debounce_are_ignored() {
+    let (button, start) = button(&[20, 25, 100, 160]);
+    let mut button = button.without_double_press();
+
+    assert_eq!(next(&mut button), Some(Gesture::ShortPress));
+    assert!(start.elapsed() >= Duration::from_millis(160));
+}
+
+#[test]
+fn short_press_waits_for_a_second_press() {
+    let (mut button, start) = button(&[20, 80]);
+
+    assert_eq!(next(&mut button), Some(Gesture::ShortPress));
+    assert!(start.elapsed() >= Duration::from_millis(80 + 150));
+}
+
+#[test]
+fn double_press() {
+    let (mut button, _) = button(&[20, 80, 140, 200]);
+
+    assert_eq!(next(&mut button), Some(Gesture::DoublePress));
+    assert_eq!(next(&mut button), None);
+}
+
+#[test]
+fn long_press_while_held() {
+    let (button, start) = button(&[20, 600, 700, 760]);
+    let mut button = button.without_double_press();
+
+    assert_eq!(next(&mut button), Some(Gesture::LongPress));
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(20 + 300) && elapsed < Duration::from_millis(600));
+
+    // releasing after a long press is no gesture on its own
+    assert_eq!(next(&mut button), Some(Gesture::ShortPress));
+    assert!(start.elapsed() >= Duration::from_millis(760));
+}
@@ -0,0 +1,85 @@
+use core::convert::Infallible;
+use embassy_time::{Duration, Instant, Timer};
+use embedded_hal::digital::{ErrorType, InputPin};
+use embedded_hal_async::digital::Wait;
+
+/// a pin toggling its level at scripted times, in milliseconds after `start`
+pub struct ScriptedPin {
+    start: Instant,
+    initial: bool,
+    toggles: &'static [u64],
+}
+
+impl ScriptedPin {
+    pub fn new(start: Instant, initial: bool, toggles: &'static [u64]) -> Self {
+        Self { start, initial, toggles }
+    }
+
+    fn at(&self, millis: u64) -> Instant {
+        self.start + Duration::from_millis(millis)
+    }
+
+    fn level(&self) -> bool {
+        let now = Instant::now();
+        let toggled = self.toggles.iter().filter(|millis| self.at(**millis) <= now).count();
+        self.initial ^ (toggled % 2 == 1)
+    }
+}
+
+impl ErrorType for ScriptedPin {
+    type Error = Infallible;
+}
+
+impl InputPin for ScriptedPin {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.level())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.level())
+    }
+}
+
+impl Wait for ScriptedPin {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        while !self.level() {
+            self.wait_for_any_edge().await?;
+        }
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        while self.level() {
+            self.wait_for_any_edge().await?;
+        }
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        loop {
+            self.wait_for_any_edge().await?;
+            if self.level() {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        loop {
+            self.wait_for_any_edge().await?;
+            if !self.level() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// waits for the next scripted toggle, forever once the script is over
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        let now = Instant::now();
+        match self.toggles.iter().map(|millis| self.at(*millis)).find(|at| *at > now) {
+            Some(at) => Timer::at(at).await,
+            None => core::future::pending().await,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use common::ScriptedPin;
+use embassy_time::{with_timeout, Duration, Instant};
+use pagrs_input::{Direction, Encoder};
+
+/// an encoder with both pins low at the start, reporting a step every full detent
+fn encoder(a: &'static [u64], b: &'static [u64]) -> Encoder<ScriptedPin, ScriptedPin> {
+    let start = Instant::now();
+    Encoder::new(ScriptedPin::new(start, false, a), ScriptedPin::new(start, false, b), 4)
+}
+
+/// the next step, `None` if there is none within a second
+fn next(encoder: &mut Encoder<ScriptedPin, ScriptedPin>) -> Option<Direction> {
+    embassy_futures::block_on(with_timeout(Duration::from_secs(1), encoder.next_step()))
+        .ok()
+        .map(Result::unwrap)
+}
+
+#[test]
+fn turning_both_ways() {
+    // 00 -> 10 -> 11 -> 01 -> 00, then back 00 -> 01 -> 11 -> 10 -> 00
+    let mut encoder = encoder(&[20, 60, 140, 180], &[40, 80, 120, 160]);
+
+    assert_eq!(next(&mut encoder), Some(Direction::Clockwise));
+    assert_eq!(next(&mut encoder), Some(Direction::CounterClockwise));
+    assert_eq!(next(&mut encoder), None);
+}
+
+#[test]
+fn bouncing_contacts_count_once() {
+    // the contact of `a` chatters before settling high
+    let mut encoder = encoder(&[20, 25, 30, 60], &[40, 80]);
+
+    assert_eq!(next(&mut encoder), Some(Direction::Clockwise));
+    assert_eq!(next(&mut encoder), None);
+}
+
+#[test]
+fn half_a_detent_is_no_step() {
+    // turned halfway and back again
+    let mut encoder = encoder(&[20, 80], &[40, 60]);
+
+    assert_eq!(next(&mut encoder), None);
+}
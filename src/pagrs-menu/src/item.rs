@@ -0,0 +1,46 @@
+use core::cell::Cell;
+
+/// a single entry of a [Menu](crate::Menu).
+///
+/// The values of toggles and numbers live in [Cell]s owned by the application, so it can read them at any time
+/// and they stay the same while navigating the menu. Submenus reference the slice of their items, so the whole
+/// menu is defined up front without any allocation.
+pub enum MenuItem<'a> {
+    /// calls the function when selected
+    Action { label: &'a str, action: &'a dyn Fn() },
+    /// flips the value when selected
+    Toggle { label: &'a str, value: &'a Cell<bool> },
+    /// starts editing the value when selected: up and down change it by `step` within `min..=max`,
+    /// select or back stop editing
+    Number { label: &'a str, value: &'a Cell<i32>, min: i32, max: i32, step: i32 },
+    /// shows the items when selected, back returns to the parent menu
+    Submenu { label: &'a str, items: &'a [MenuItem<'a>] },
+}
+
+impl<'a> MenuItem<'a> {
+    pub const fn action(label: &'a str, action: &'a dyn Fn()) -> Self {
+        MenuItem::Action { label, action }
+    }
+
+    pub const fn toggle(label: &'a str, value: &'a Cell<bool>) -> Self {
+        MenuItem::Toggle { label, value }
+    }
+
+    pub const fn number(label: &'a str, value: &'a Cell<i32>, min: i32, max: i32, step: i32) -> Self {
+        MenuItem::Number { label, value, min, max, step }
+    }
+
+    pub const fn submenu(label: &'a str, items: &'a [MenuItem<'a>]) -> Self {
+        MenuItem::Submenu { label, items }
+    }
+
+    /// the text shown for the item
+    pub fn label(&self) -> &'a str {
+        match self {
+            MenuItem::Action { label, .. }
+            | MenuItem::Toggle { label, .. }
+            | MenuItem::Number { label, .. }
+            | MenuItem::Submenu { label, .. } => label,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![no_std]
+
+mod item;
+mod menu;
+
+pub use item::MenuItem;
+pub use menu::Menu;
@@ -0,0 +1,275 @@
+use core::fmt::Write;
+use display_interface::DisplayError;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::Drawable;
+use embedded_graphics_core::geometry::{Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::Rectangle;
+use heapless::{String, Vec};
+use pagrs_core::{InputEvent, Page};
+use pagrs_core::fmt::{debug, trace};
+use crate::item::MenuItem;
+
+/// one list of items on the way from the top menu to the submenu shown
+struct Level<'a> {
+    items: &'a [MenuItem<'a>],
+    selected: usize,
+    scroll: usize,
+}
+
+impl<'a> Level<'a> {
+    fn new(items: &'a [MenuItem<'a>]) -> Self {
+        Self {
+            items,
+            selected: 0,
+            scroll: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    Up,
+    Down,
+}
+
+/// an interactive menu, showing a scrollable list of [items](MenuItem) with the selected one highlighted.
+///
+/// The menu is driven by the [input events](InputEvent) forwarded by the [PageRotator](pagrs_core::PageRotator):
+/// - up / down / turning an encoder: move the selection, or change the value while editing a number
+///   (up and clockwise increase it)
+/// - select: call the action, flip the toggle, start or stop editing the number, or open the submenu
+/// - back: stop editing the number or return to the parent menu
+///
+/// Moving beyond the first or last item of the top menu and back in the top menu are not consumed, so the
+/// [PageRotator](pagrs_core::PageRotator) can use them to navigate to another page.
+///
+/// ## type parameters
+/// - `DEPTH`: the maximum depth of nested submenus, including the top menu
+///
+/// ## example
+/// ```rust
+/// use core::cell::Cell;
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_core::{InputEvent, Page};
+/// use pagrs_core::FrameBuffer;
+/// use pagrs_menu::{Menu, MenuItem};
+///
+/// let sound = Cell::new(false);
+/// let volume = Cell::new(5);
+/// let audio = [
+///     MenuItem::toggle("Sound", &sound),
+///     MenuItem::number("Volume", &volume, 0, 10, 1),
+/// ];
+/// let items = [
+///     MenuItem::action("Reboot", &|| {}),
+///     MenuItem::submenu("Audio", &audio),
+/// ];
+/// let mut menu = Menu::<4>::new(&items, &FONT_6X10);
+///
+/// // open the audio submenu and switch the sound on
+/// for event in [InputEvent::Down, InputEvent::Select, InputEvent::Select] {
+///     assert!(Page::<FrameBuffer<128, 8>>::input(&mut menu, event));
+/// }
+/// assert!(sound.get());
+///
+/// // raise the volume by two
+/// for event in [InputEvent::Down, InputEvent::Select, InputEvent::Up, InputEvent::Rotate(1), InputEvent::Select] {
+///     Page::<FrameBuffer<128, 8>>::input(&mut menu, event);
+/// }
+/// assert_eq!(volume.get(), 7);
+///
+/// // back to the top menu, leaving it is up to the page rotator
+/// assert!(Page::<FrameBuffer<128, 8>>::input(&mut menu, InputEvent::Back));
+/// assert!(!Page::<FrameBuffer<128, 8>>::input(&mut menu, InputEvent::Back));
+/// ```
+pub struct Menu<'a, const DEPTH: usize = 4> {
+    levels: Vec<Level<'a>, DEPTH>,
+    editing: bool,
+    font: &'a MonoFont<'a>,
+}
+
+impl<'a, const DEPTH: usize> Menu<'a, DEPTH> {
+    /// create a new [Menu] showing the `items` as top menu
+    pub fn new(items: &'a [MenuItem<'a>], font: &'a MonoFont<'a>) -> Self {
+        let mut levels = Vec::new();
+        // a menu with a depth of 0 can't show anything, so the top menu is simply missing
+        let _ = levels.push(Level::new(items));
+
+        Self {
+            levels,
+            editing: false,
+            font,
+        }
+    }
+
+    /// the item currently selected
+    pub fn selected(&self) -> Option<&MenuItem<'a>> {
+        self.levels
+            .last()
+            .and_then(|level| level.items.get(level.selected))
+    }
+
+    /// return to the first item of the top menu
+    pub fn reset(&mut self) {
+        self.levels.truncate(1);
+        self.editing = false;
+        if let Some(level) = self.levels.last_mut() {
+            level.selected = 0;
+            level.scroll = 0;
+        }
+    }
+
+    /// apply the event to the menu, returning if it was consumed
+    fn handle(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Select => self.select(),
+            InputEvent::Back => self.back(),
+            InputEvent::Up if self.editing => self.edit(1),
+            InputEvent::Down if self.editing => self.edit(-1),
+            InputEvent::Rotate(steps) if self.editing => self.edit(steps as i32),
+            InputEvent::Up => self.move_selection(Move::Up, 1),
+            InputEvent::Down => self.move_selection(Move::Down, 1),
+            InputEvent::Rotate(steps) if steps < 0 => self.move_selection(Move::Up, steps.unsigned_abs() as usize),
+            InputEvent::Rotate(steps) => self.move_selection(Move::Down, steps as usize),
+        }
+    }
+
+    /// change the number being edited by `steps` times its step
+    fn edit(&mut self, steps: i32) -> bool {
+        if let Some(MenuItem::Number { label, value, min, max, step }) = self.selected() {
+            let changed = value.get().saturating_add(step.saturating_mul(steps));
+            value.set(changed.clamp(*min, *max));
+            trace!("menu item '{}' changed to {}", label, value.get());
+        }
+
+        true
+    }
+
+    fn move_selection(&mut self, direction: Move, steps: usize) -> bool {
+        let is_top = self.levels.len() == 1;
+        let Some(level) = self.levels.last_mut() else {
+            return false;
+        };
+
+        let last = level.items.len().saturating_sub(1);
+        level.selected = match direction {
+            Move::Up if level.selected == 0 && is_top => return false,
+            Move::Down if level.selected == last && is_top => return false,
+            Move::Up => level.selected.saturating_sub(steps),
+            Move::Down => level.selected.saturating_add(steps).min(last),
+        };
+
+        true
+    }
+
+    fn select(&mut self) -> bool {
+        if self.editing {
+            self.editing = false;
+            return true;
+        }
+
+        if let Some(item) = self.selected() {
+            debug!("menu item '{}' selected", item.label());
+        }
+
+        match self.selected() {
+            Some(MenuItem::Action { action, .. }) => action(),
+            Some(MenuItem::Toggle { value, .. }) => value.set(!value.get()),
+            Some(MenuItem::Number { .. }) => self.editing = true,
+            Some(MenuItem::Submenu { items, .. }) => {
+                let items = *items;
+                // submenus nested deeper than the menu supports can't be opened
+                let _ = self.levels.push(Level::new(items));
+            }
+            None => {}
+        }
+
+        true
+    }
+
+    fn back(&mut self) -> bool {
+        if self.editing {
+            self.editing = false;
+            return true;
+        }
+
+        if self.levels.len() <= 1 {
+            return false;
+        }
+
+        self.levels.pop();
+        true
+    }
+
+    fn draw_items<D: DrawTarget<Color = BinaryColor, Error = DisplayError>>(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let area = display.bounding_box();
+        let row_height = self.font.character_size.height.max(1);
+        let rows = (area.size.height / row_height).max(1) as usize;
+        let editing = self.editing;
+
+        let Some(level) = self.levels.last_mut() else {
+            return Ok(());
+        };
+
+        // keep the selected item visible
+        if level.selected < level.scroll {
+            level.scroll = level.selected;
+        } else if level.selected >= level.scroll + rows {
+            level.scroll = level.selected + 1 - rows;
+        }
+
+        let left = TextStyleBuilder::new().baseline(Baseline::Top).build();
+        let right = TextStyleBuilder::new().baseline(Baseline::Top).alignment(Alignment::Right).build();
+
+        for (row, (index, item)) in level.items.iter().enumerate().skip(level.scroll).take(rows).enumerate() {
+            let top_left = area.top_left + Point::new(0, (row as u32 * row_height) as i32);
+            let highlighted = index == level.selected;
+
+            let color = if highlighted {
+                display.fill_solid(&Rectangle::new(top_left, Size::new(area.size.width, row_height)), BinaryColor::On)?;
+                BinaryColor::Off
+            } else {
+                BinaryColor::On
+            };
+            let style = MonoTextStyle::new(self.font, color);
+
+            Text::with_text_style(item.label(), top_left + Point::new(1, 0), style, left).draw(display)?;
+
+            let mut value = String::<16>::new();
+            let _ = match item {
+                MenuItem::Action { .. } => Ok(()),
+                MenuItem::Toggle { value: toggle, .. } => value.write_str(if toggle.get() { "[x]" } else { "[ ]" }),
+                MenuItem::Number { value: number, .. } if highlighted && editing => write!(value, "<{}>", number.get()),
+                MenuItem::Number { value: number, .. } => write!(value, "{}", number.get()),
+                MenuItem::Submenu { .. } => value.write_str(">"),
+            };
+            let right_edge = top_left + Point::new(area.size.width as i32 - 2, 0);
+            Text::with_text_style(&value, right_edge, style, right).draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, const DEPTH: usize, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for Menu<'a, DEPTH> {
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        self.draw_items(display)
+    }
+
+    fn deactivated(&mut self) -> Result<(), DisplayError> {
+        self.editing = false;
+
+        Ok(())
+    }
+
+    fn frames_per_second(&self) -> u8 {
+        10
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        self.handle(event)
+    }
+}
@@ -0,0 +1,198 @@
+use core::cell::Cell;
+use core::fmt::{Display, Formatter};
+use embassy_time::{Duration, Instant};
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// a time of the day, with a precision of seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimeOfDay {
+    seconds: u32,
+}
+
+impl TimeOfDay {
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay { seconds: 0 };
+
+    /// create a new [TimeOfDay]. Panics if the hour, minute or second is out of range, which fails the build
+    /// when used in a constant.
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Self {
+        assert!(hour < 24 && minute < 60 && second < 60, "time of day out of range");
+        Self {
+            seconds: hour as u32 * 3600 + minute as u32 * 60 + second as u32,
+        }
+    }
+
+    /// create a new [TimeOfDay] from the seconds passed since midnight, wrapping around after a day
+    pub const fn from_seconds_since_midnight(seconds: u32) -> Self {
+        Self {
+            seconds: seconds % SECONDS_PER_DAY,
+        }
+    }
+
+    pub const fn seconds_since_midnight(&self) -> u32 {
+        self.seconds
+    }
+
+    pub const fn hour(&self) -> u8 {
+        (self.seconds / 3600) as u8
+    }
+
+    pub const fn minute(&self) -> u8 {
+        (self.seconds / 60 % 60) as u8
+    }
+
+    pub const fn second(&self) -> u8 {
+        (self.seconds % 60) as u8
+    }
+
+    /// how long from this time until `later` is reached, wrapping around midnight
+    pub fn until(&self, later: TimeOfDay) -> Duration {
+        let seconds = (later.seconds + SECONDS_PER_DAY - self.seconds) % SECONDS_PER_DAY;
+        Duration::from_secs(seconds as u64)
+    }
+
+    /// this time advanced by `duration`, wrapping around midnight
+    pub fn after(&self, duration: Duration) -> TimeOfDay {
+        let seconds = (duration.as_secs() % SECONDS_PER_DAY as u64) as u32;
+        TimeOfDay::from_seconds_since_midnight(self.seconds + seconds)
+    }
+}
+
+/// formatted as `hh:mm:ss`
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour(), self.minute(), self.second())
+    }
+}
+
+/// a source of the current wall clock time, used by the [Scheduler](crate::Scheduler)
+#[allow(async_fn_in_trait)]
+pub trait Clock {
+    /// the current time of the day, `None` if the clock is not set (yet)
+    async fn now(&mut self) -> Option<TimeOfDay>;
+}
+
+/// a clock for tests, showing the time it is set to
+///
+/// ## example
+/// ```rust
+/// use embassy_time::Duration;
+/// use pagrs_schedule::{Clock, FakeClock, TimeOfDay};
+///
+/// let clock = FakeClock::new(TimeOfDay::new(23, 30, 0));
+/// clock.advance(Duration::from_secs(45 * 60));
+///
+/// let now = embassy_futures::block_on((&clock).now());
+/// assert_eq!(now, Some(TimeOfDay::new(0, 15, 0)));
+/// ```
+#[derive(Debug, Default)]
+pub struct FakeClock {
+    time: Cell<Option<TimeOfDay>>,
+}
+
+impl FakeClock {
+    /// create a new [FakeClock] showing `time`
+    pub fn new(time: TimeOfDay) -> Self {
+        Self {
+            time: Cell::new(Some(time)),
+        }
+    }
+
+    /// create a new [FakeClock] which is not set
+    pub fn unset() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&self, time: TimeOfDay) {
+        self.time.set(Some(time));
+    }
+
+    /// move the time forward, if the clock is set
+    pub fn advance(&self, duration: Duration) {
+        self.time.set(self.time.get().map(|time| time.after(duration)));
+    }
+}
+
+impl Clock for FakeClock {
+    async fn now(&mut self) -> Option<TimeOfDay> {
+        self.time.get()
+    }
+}
+
+/// the clock can be shared with the [Scheduler](crate::Scheduler), to change the time while it runs
+impl Clock for &FakeClock {
+    async fn now(&mut self) -> Option<TimeOfDay> {
+        self.time.get()
+    }
+}
+
+/// a clock without any hardware, counting from a time it is set to, e.g. once read from an RTC or received via
+/// network. It is as precise as the time driver of embassy.
+#[derive(Debug, Default)]
+pub struct MonotonicClock {
+    reference: Cell<Option<(Instant, TimeOfDay)>>,
+}
+
+impl MonotonicClock {
+    /// create a new [MonotonicClock] which is not set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// set the clock to `time`, as of now
+    pub fn set(&self, time: TimeOfDay) {
+        self.reference.set(Some((Instant::now(), time)));
+    }
+}
+
+impl Clock for MonotonicClock {
+    async fn now(&mut self) -> Option<TimeOfDay> {
+        (&*self).now().await
+    }
+}
+
+impl Clock for &MonotonicClock {
+    async fn now(&mut self) -> Option<TimeOfDay> {
+        self.reference.get().map(|(since, time)| time.after(since.elapsed()))
+    }
+}
+
+/// a clock backed by the real time clock of the RP2040.
+///
+/// The RTC has to be set once after power up, e.g. via [`set`](RtcClock::set), until then the clock is not set.
+#[cfg(feature = "embassy-rp")]
+pub struct RtcClock<'d, T: embassy_rp::rtc::Instance> {
+    rtc: embassy_rp::rtc::Rtc<'d, T>,
+}
+
+#[cfg(feature = "embassy-rp")]
+impl<'d, T: embassy_rp::rtc::Instance> RtcClock<'d, T> {
+    pub fn new(rtc: embassy_rp::rtc::Rtc<'d, T>) -> Self {
+        Self { rtc }
+    }
+
+    /// set the RTC to `time` on the given day
+    pub fn set(&mut self, date: embassy_rp::rtc::DateTime, time: TimeOfDay) -> Result<(), embassy_rp::rtc::RtcError> {
+        self.rtc.set_datetime(embassy_rp::rtc::DateTime {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            ..date
+        })
+    }
+
+    /// give back the RTC
+    pub fn release(self) -> embassy_rp::rtc::Rtc<'d, T> {
+        self.rtc
+    }
+}
+
+#[cfg(feature = "embassy-rp")]
+impl<'d, T: embassy_rp::rtc::Instance> Clock for RtcClock<'d, T> {
+    async fn now(&mut self) -> Option<TimeOfDay> {
+        let now = self.rtc.now().ok()?;
+        let seconds = now.hour as u32 * 3600 + now.minute as u32 * 60 + now.second as u32;
+        Some(TimeOfDay::from_seconds_since_midnight(seconds))
+    }
+}
@@ -0,0 +1,11 @@
+#![no_std]
+
+mod clock;
+mod schedule;
+mod scheduler;
+
+pub use clock::{Clock, FakeClock, MonotonicClock, TimeOfDay};
+#[cfg(feature = "embassy-rp")]
+pub use clock::RtcClock;
+pub use schedule::{Playlist, Schedule, Slot};
+pub use scheduler::Scheduler;
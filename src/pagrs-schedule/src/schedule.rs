@@ -0,0 +1,109 @@
+use crate::clock::TimeOfDay;
+
+/// a named set of pages of the [PageRotator](pagrs_core::PageRotator), shown during a [Slot] of a [Schedule]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Playlist<'a> {
+    pub name: &'a str,
+    /// the indices of the pages shown, in order of registration to the [PageRotator](pagrs_core::PageRotator).
+    /// All other pages are disabled.
+    pub pages: &'a [usize],
+    /// the brightness of the display while the playlist is shown, `None` to keep it as it is
+    pub brightness: Option<u8>,
+}
+
+impl<'a> Playlist<'a> {
+    pub const fn new(name: &'a str, pages: &'a [usize]) -> Self {
+        Self {
+            name,
+            pages,
+            brightness: None,
+        }
+    }
+
+    /// show the playlist with the given brightness
+    pub const fn with_brightness(self, brightness: u8) -> Self {
+        Self {
+            brightness: Some(brightness),
+            ..self
+        }
+    }
+
+    /// if the page with the given index is part of the playlist
+    pub fn contains(&self, page: usize) -> bool {
+        self.pages.contains(&page)
+    }
+}
+
+/// the [Playlist] shown from a time of the day on, until the next slot of the [Schedule] starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Slot<'a> {
+    pub from: TimeOfDay,
+    pub playlist: &'a Playlist<'a>,
+}
+
+impl<'a> Slot<'a> {
+    pub const fn new(from: TimeOfDay, playlist: &'a Playlist<'a>) -> Self {
+        Self { from, playlist }
+    }
+}
+
+/// which [Playlist] to show at which time of the day.
+///
+/// Each [Slot] lasts until the next one starts, the last slot of the day lasts until the first one of the next day.
+///
+/// ## example
+/// ```rust
+/// use pagrs_schedule::{Playlist, Schedule, Slot, TimeOfDay};
+///
+/// const MORNING: Playlist = Playlist::new("morning", &[0, 1, 2]);
+/// const BUSINESS: Playlist = Playlist::new("business", &[1, 2, 3, 4]);
+/// const NIGHT: Playlist = Playlist::new("night", &[5]).with_brightness(8);
+///
+/// const SLOTS: [Slot; 3] = [
+///     Slot::new(TimeOfDay::new(6, 0, 0), &MORNING),
+///     Slot::new(TimeOfDay::new(9, 0, 0), &BUSINESS),
+///     Slot::new(TimeOfDay::new(18, 30, 0), &NIGHT),
+/// ];
+/// let schedule = Schedule::new(&SLOTS);
+///
+/// assert_eq!(schedule.active(TimeOfDay::new(7, 15, 0)).unwrap().name, "morning");
+/// assert_eq!(schedule.active(TimeOfDay::new(18, 30, 0)).unwrap().name, "night");
+/// // the night lasts until the morning
+/// assert_eq!(schedule.active(TimeOfDay::new(3, 0, 0)).unwrap().name, "night");
+/// assert_eq!(schedule.next_change(TimeOfDay::new(3, 0, 0)), Some(TimeOfDay::new(6, 0, 0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Schedule<'a> {
+    slots: &'a [Slot<'a>],
+}
+
+impl<'a> Schedule<'a> {
+    /// create a new [Schedule] of the `slots`, in any order
+    pub const fn new(slots: &'a [Slot<'a>]) -> Self {
+        Self { slots }
+    }
+
+    /// the slot active at the given time, `None` if the schedule has no slots
+    pub fn active_slot(&self, now: TimeOfDay) -> Option<&'a Slot<'a>> {
+        let latest_started = self.slots.iter().filter(|slot| slot.from <= now).max_by_key(|slot| slot.from);
+        // before the first slot of the day the last slot of the previous day is still active
+        latest_started.or_else(|| self.slots.iter().max_by_key(|slot| slot.from))
+    }
+
+    /// the playlist to show at the given time, `None` if the schedule has no slots
+    pub fn active(&self, now: TimeOfDay) -> Option<&'a Playlist<'a>> {
+        self.active_slot(now).map(|slot| slot.playlist)
+    }
+
+    /// when the next slot starts after the given time, `None` if the schedule has no slots
+    pub fn next_change(&self, now: TimeOfDay) -> Option<TimeOfDay> {
+        self.slots
+            .iter()
+            .map(|slot| slot.from)
+            .filter(|from| *from != now)
+            .min_by_key(|from| now.until(*from))
+    }
+}
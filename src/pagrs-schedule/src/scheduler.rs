@@ -0,0 +1,95 @@
+use embassy_time::{Duration, Timer};
+use pagrs_core::PageController;
+use pagrs_core::fmt::debug;
+use crate::clock::Clock;
+use crate::schedule::{Playlist, Schedule};
+
+/// how often the clock is read at most, so changes of the clock (e.g. a daylight saving time switch) are noticed
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// show the [Playlist] of the [Schedule] active at the time of the [Clock], by enabling and disabling the pages of
+/// the [PageRotator](pagrs_core::PageRotator) via the [PageController].
+///
+/// **info**: only the first 32 pages can be disabled, see [`PageController::set_enabled_pages`]. The pages of a
+/// playlist are switched at once and persisted like any other change, if the [PageRotator](pagrs_core::PageRotator)
+/// has a storage.
+///
+/// ## example
+/// ```rust,ignore
+/// const DAY: Playlist = Playlist::new("day", &[0, 1, 2]);
+/// const NIGHT: Playlist = Playlist::new("night", &[3]).with_brightness(8);
+/// const SLOTS: [Slot; 2] = [
+///     Slot::new(TimeOfDay::new(7, 0, 0), &DAY),
+///     Slot::new(TimeOfDay::new(22, 0, 0), &NIGHT),
+/// ];
+///
+/// #[embassy_executor::task]
+/// async fn schedule(rtc: Rtc<'static, RTC>) {
+///     let mut scheduler = Scheduler::new(Schedule::new(&SLOTS), RtcClock::new(rtc));
+///     scheduler.run().await;
+/// }
+/// ```
+pub struct Scheduler<'a, C> {
+    schedule: Schedule<'a>,
+    clock: C,
+    controller: PageController,
+    active: Option<&'a Playlist<'a>>,
+}
+
+impl<'a, C: Clock> Scheduler<'a, C> {
+    /// create a new [Scheduler] following the `schedule` according to the `clock`
+    pub fn new(schedule: Schedule<'a>, clock: C) -> Self {
+        Self {
+            schedule,
+            clock,
+            controller: PageController::new(),
+            active: None,
+        }
+    }
+
+    /// the playlist shown currently, `None` until the clock is set
+    pub fn active(&self) -> Option<&'a Playlist<'a>> {
+        self.active
+    }
+
+    /// keep showing the playlist of the current time. This method never returns.
+    pub async fn run(&mut self) -> ! {
+        loop {
+            let wait = match self.update().await {
+                Some(until_change) => until_change.min(CHECK_INTERVAL),
+                None => CHECK_INTERVAL,
+            };
+            Timer::after(wait).await;
+        }
+    }
+
+    /// show the playlist of the current time, if it changed. Returns how long until the next slot starts,
+    /// [`Duration::MAX`] if the schedule has only one slot and `None` if the clock is not set or the schedule has no slots.
+    pub async fn update(&mut self) -> Option<Duration> {
+        let now = self.clock.now().await?;
+        let playlist = self.schedule.active(now)?;
+
+        if self.active != Some(playlist) {
+            debug!("showing playlist '{}' as of {}", playlist.name, now);
+            self.show(playlist).await;
+            self.active = Some(playlist);
+        }
+
+        // a schedule of a single slot never changes
+        let until_change = match self.schedule.next_change(now) {
+            Some(next_change) => now.until(next_change).max(Duration::from_secs(1)),
+            None => Duration::MAX,
+        };
+        Some(until_change)
+    }
+
+    async fn show(&self, playlist: &Playlist<'a>) {
+        // all pages are switched at once, so the rotation always has a page to continue with
+        let enabled = playlist.pages.iter().filter(|page| **page < 32).fold(0, |enabled, page| enabled | 1 << page);
+        self.controller.set_enabled_pages(enabled).await;
+
+        if let Some(brightness) = playlist.brightness {
+            self.controller.set_brightness(brightness).await;
+        }
+    }
+}
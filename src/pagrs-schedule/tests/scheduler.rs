@@ -0,0 +1,130 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+use display_interface::DisplayError;
+use embassy_futures::select::{select, Either};
+use embassy_time::{with_timeout, Duration};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use pagrs_core::{Backend, MemoryStorage, Page, PageController, PageRotator, StateStorage, StorageError};
+use pagrs_schedule::{FakeClock, Playlist, Schedule, Scheduler, Slot, TimeOfDay};
+
+const DAY: Playlist = Playlist::new("day", &[0, 1]);
+const NIGHT: Playlist = Playlist::new("night", &[2]).with_brightness(8);
+const SLOTS: [Slot; 2] = [
+    Slot::new(TimeOfDay::new(6, 0, 0), &DAY),
+    Slot::new(TimeOfDay::new(22, 0, 0), &NIGHT),
+];
+
+/// how often the rotator saved its state
+static SAVES: AtomicUsize = AtomicUsize::new(0);
+
+/// a display showing nothing
+struct Blank;
+
+impl OriginDimensions for Blank {
+    fn size(&self) -> Size {
+        Size::new(8, 8)
+    }
+}
+
+impl DrawTarget for Blank {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I: IntoIterator<Item = Pixel<BinaryColor>>>(&mut self, _pixels: I) -> Result<(), DisplayError> {
+        Ok(())
+    }
+}
+
+impl Backend for Blank {
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        Ok(())
+    }
+
+    fn clear_buffer(&mut self) {}
+
+    async fn flush(&mut self) -> Result<(), DisplayError> {
+        Ok(())
+    }
+}
+
+struct Empty;
+
+impl Page<Blank> for Empty {
+    fn render(&mut self, _display: &mut Blank) -> Result<(), DisplayError> {
+        Ok(())
+    }
+}
+
+/// a storage counting the saves
+struct Counting(MemoryStorage<8>);
+
+impl StateStorage for Counting {
+    fn load(&mut self, buffer: &mut [u8]) -> Result<usize, StorageError> {
+        self.0.load(buffer)
+    }
+
+    fn save(&mut self, data: &[u8]) -> Result<(), StorageError> {
+        SAVES.fetch_add(1, Ordering::Relaxed);
+        self.0.save(data)
+    }
+}
+
+async fn enabled(controller: &PageController) -> [bool; 4] {
+    let mut enabled = [false; 4];
+    for (index, page) in enabled.iter_mut().enumerate() {
+        *page = controller.page_status(index).await.unwrap().enabled;
+    }
+    enabled
+}
+
+#[test]
+fn follow_the_schedule_across_midnight() {
+    let mut storage = Counting(MemoryStorage::new());
+    let mut pages = [Empty, Empty, Empty, Empty];
+    let mut rotator = PageRotator::<4, _>::with_storage(Blank, &mut storage);
+    // the pages stay long enough to never rotate on their own during the test
+    for page in pages.iter_mut() {
+        rotator.add_page_with_duration(page, Duration::from_secs(3600)).ok().unwrap();
+    }
+    let controller = rotator.controller();
+    let clock = FakeClock::new(TimeOfDay::new(21, 59, 59));
+    let mut scheduler = Scheduler::new(Schedule::new(&SLOTS), &clock);
+
+    let script = async {
+        assert_eq!(scheduler.update().await, Some(Duration::from_secs(1)));
+        assert_eq!(scheduler.active(), Some(&DAY));
+        assert_eq!(enabled(&controller).await, [true, true, false, false]);
+        assert_eq!(SAVES.load(Ordering::Relaxed), 1);
+
+        clock.set(TimeOfDay::new(22, 0, 0));
+        assert_eq!(scheduler.update().await, Some(Duration::from_secs(8 * 3600)));
+        assert_eq!(scheduler.active(), Some(&NIGHT));
+        assert_eq!(enabled(&controller).await, [false, false, true, false]);
+        let status = controller.status().await;
+        assert_eq!(status.brightness, Some(8));
+        // the page shown got disabled, the rotation moved on to the only page left
+        assert_eq!(status.current_page, 2);
+        // the pages, the brightness and the page moved on to, instead of one save per page
+        assert_eq!(SAVES.load(Ordering::Relaxed), 4);
+
+        // the night lasts past midnight, nothing changes
+        clock.advance(Duration::from_secs(2 * 3600 + 30));
+        assert_eq!(scheduler.update().await, Some(Duration::from_secs(6 * 3600 - 30)));
+        assert_eq!(scheduler.active(), Some(&NIGHT));
+        assert_eq!(enabled(&controller).await, [false, false, true, false]);
+        assert_eq!(SAVES.load(Ordering::Relaxed), 4);
+
+        clock.set(TimeOfDay::new(6, 0, 0));
+        assert_eq!(scheduler.update().await, Some(Duration::from_secs(16 * 3600)));
+        assert_eq!(scheduler.active(), Some(&DAY));
+        assert_eq!(enabled(&controller).await, [true, true, false, false]);
+        assert_eq!(controller.status().await.current_page, 0);
+    };
+
+    let rotation = async {
+        rotator.init().await.unwrap();
+        rotator.run().await
+    };
+    let result = embassy_futures::block_on(select(rotation, with_timeout(Duration::from_secs(10), script)));
+    assert!(matches!(result, Either::Second(Ok(()))), "the rotator didn't answer");
+}
@@ -0,0 +1,344 @@
+use core::fmt::Write;
+use display_interface::DisplayError;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::primitives::Rectangle;
+use heapless::String;
+use pagrs_core::{Page, Refresh};
+use crate::text_box::width;
+
+/// the most characters of a formatted value, including sign, decimal point and padding
+const MAX_CHARACTERS: usize = 24;
+/// the smallest height of the digits, in pixels
+const MIN_DIGIT_HEIGHT: u32 = 5;
+
+/// the segments of the digits 0 to 9, bit 0 being the top segment `a`, clockwise to `f` and the middle segment `g`
+const SEGMENTS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+/// the middle segment, showing a minus
+const MINUS_SEGMENTS: u8 = 0x40;
+
+/// the rows of the digits 0 to 9 in a grid of 3x5 blocks, top to bottom, the left block being the highest bit
+const BLOCKS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+/// the rows of a minus in a grid of 3x5 blocks
+const MINUS_BLOCKS: [u8; 5] = [0b000, 0b000, 0b111, 0b000, 0b000];
+
+/// how the digits of a [BigNumber] are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DigitStyle {
+    /// like the segments of a digital clock
+    SevenSegment,
+    /// like a pixel font of 3x5 blocks
+    Block,
+}
+
+/// a page showing a single value in digits as large as the display allows, e.g. a temperature readable from across
+/// the room.
+///
+/// The value is queried on each frame, formatted with a fixed amount of decimals and drawn centered, followed by an
+/// optional unit label in the bottom right. Negative values get a minus in front.
+///
+/// The digits are sized to fit the widest value expected: at least `min_width` digits, or exactly `max_width` digits
+/// if set. Values with more digits than `max_width` are shown as dashes, as are values which are not a number.
+///
+/// ## type parameters
+/// - `F`: the callback querying the value
+/// - `C`: the character style of the unit label, a `MonoTextStyle` unless created [`styled`](BigNumber::styled)
+///
+/// ## example
+/// ```rust
+/// use embedded_graphics::mono_font::iso_8859_1::FONT_6X10;
+/// use pagrs_core::Refresh;
+/// use pagrs_text::{BigNumber, DigitStyle};
+///
+/// let mut temperature = BigNumber::new(|| 21.5, &FONT_6X10)
+///     .with_unit("°C")
+///     .with_decimals(1)
+///     .with_width(3, Some(4))
+///     .with_digits(DigitStyle::SevenSegment)
+///     .with_refresh(Refresh::FramesPerSecond(2));
+/// ```
+#[derive(Debug)]
+pub struct BigNumber<'a, F, C = MonoTextStyle<'a, BinaryColor>>
+where
+    F: Fn() -> f32,
+{
+    query_value: F,
+    label_style: C,
+    unit: &'a str,
+    digits: DigitStyle,
+    decimals: u8,
+    min_width: u8,
+    max_width: Option<u8>,
+    refresh: Refresh,
+}
+
+impl<'a, F> BigNumber<'a, F>
+where
+    F: Fn() -> f32,
+{
+    /// create a new [BigNumber] in seven segment digits, without decimals, labeling the unit in the `font`
+    pub fn new(query_value: F, font: &'a MonoFont<'a>) -> Self {
+        Self::styled(query_value, MonoTextStyle::new(font, BinaryColor::On))
+    }
+}
+
+impl<'a, F, C> BigNumber<'a, F, C>
+where
+    F: Fn() -> f32,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    /// create a new [BigNumber] in seven segment digits, without decimals, labeling the unit in any character `style`
+    pub fn styled(query_value: F, label_style: C) -> Self {
+        Self {
+            query_value,
+            label_style,
+            unit: "",
+            digits: DigitStyle::SevenSegment,
+            decimals: 0,
+            min_width: 1,
+            max_width: None,
+            refresh: Refresh::FramesPerSecond(1),
+        }
+    }
+
+    /// label the value with the `unit`, e.g. `km/h`
+    pub fn with_unit(self, unit: &'a str) -> Self {
+        Self { unit, ..self }
+    }
+
+    pub fn with_digits(self, digits: DigitStyle) -> Self {
+        Self { digits, ..self }
+    }
+
+    /// show the given amount of digits after the decimal point
+    pub fn with_decimals(self, decimals: u8) -> Self {
+        Self { decimals, ..self }
+    }
+
+    /// reserve the room for at least `min` digits, and at most `max` digits, including the decimals
+    pub fn with_width(self, min: u8, max: Option<u8>) -> Self {
+        Self {
+            min_width: min,
+            max_width: max,
+            ..self
+        }
+    }
+
+    /// how often the value is queried and shown again, once per second by default
+    pub fn with_refresh(self, refresh: Refresh) -> Self {
+        Self { refresh, ..self }
+    }
+
+    /// the value formatted, padded with spaces in front to the width of the digits shown
+    fn format(&self, value: f32) -> String<MAX_CHARACTERS> {
+        let mut formatted = String::<MAX_CHARACTERS>::new();
+        let fits = value.is_finite() && write!(formatted, "{:.*}", self.decimals as usize, value).is_ok();
+        let cells = cells(&formatted);
+        if !fits || self.max_width.is_some_and(|max| cells > max as usize) {
+            // too wide or not a number at all
+            formatted.clear();
+            for _ in 0..self.width(1) {
+                let _ = formatted.push('-');
+            }
+            return formatted;
+        }
+
+        let mut padded = String::<MAX_CHARACTERS>::new();
+        for _ in cells..self.width(cells) {
+            let _ = padded.push(' ');
+        }
+        let _ = padded.push_str(&formatted);
+        padded
+    }
+
+    /// the amount of digits the room is reserved for, when showing the given amount of digits
+    fn width(&self, cells: usize) -> usize {
+        match self.max_width {
+            Some(max) => max as usize,
+            None => cells.max(self.min_width as usize),
+        }
+    }
+}
+
+impl<'a, F, C, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for BigNumber<'a, F, C>
+where
+    F: Fn() -> f32,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let value = (self.query_value)();
+        let text = self.format(value);
+        let area = display.bounding_box();
+        let label_width = if self.unit.is_empty() { 0 } else { width(&self.label_style, self.unit) };
+        let points = text.chars().filter(|char| *char == '.').count() as u32;
+
+        // the largest digits leaving room for all of them and the label
+        let mut metrics = Metrics::new(self.digits, MIN_DIGIT_HEIGHT);
+        for height in (MIN_DIGIT_HEIGHT..=area.size.height.max(MIN_DIGIT_HEIGHT)).rev() {
+            metrics = Metrics::new(self.digits, height);
+            let label = if label_width > 0 { metrics.gap + label_width } else { 0 };
+            if metrics.width(cells(&text) as u32, points) + label <= area.size.width {
+                break;
+            }
+        }
+
+        let label = if label_width > 0 { metrics.gap + label_width } else { 0 };
+        let total = metrics.width(cells(&text) as u32, points) + label;
+        let mut x = area.top_left.x + (area.size.width.saturating_sub(total) / 2) as i32;
+        let top = area.top_left.y + (area.size.height.saturating_sub(metrics.height) / 2) as i32;
+        for char in text.chars() {
+            let origin = Point::new(x, top);
+            match char {
+                '.' => {
+                    let point = Size::new(metrics.point, metrics.point);
+                    let bottom = origin + Point::new(0, (metrics.height - metrics.point) as i32);
+                    display.fill_solid(&Rectangle::new(bottom, point), BinaryColor::On)?;
+                    x += (metrics.point + metrics.gap) as i32;
+                }
+                _ => {
+                    match self.digits {
+                        DigitStyle::SevenSegment => draw_segments(display, origin, &metrics, char)?,
+                        DigitStyle::Block => draw_blocks(display, origin, &metrics, char)?,
+                    }
+                    x += (metrics.digit + metrics.gap) as i32;
+                }
+            }
+        }
+
+        if label_width > 0 {
+            let bottom = top + metrics.height as i32 - self.label_style.line_height() as i32;
+            self.label_style.draw_string(self.unit, Point::new(x, bottom), Baseline::Top, display)?;
+        }
+
+        Ok(())
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.refresh
+    }
+}
+
+/// the dimensions of the digits, in pixels
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    height: u32,
+    digit: u32,
+    /// the width of a segment, or the size of a block
+    stroke: u32,
+    point: u32,
+    gap: u32,
+}
+
+impl Metrics {
+    /// the digits at most `height` pixels high
+    fn new(digits: DigitStyle, height: u32) -> Self {
+        match digits {
+            DigitStyle::SevenSegment => {
+                let stroke = (height / 8).max(1);
+                Self {
+                    height,
+                    digit: height / 2,
+                    stroke,
+                    point: stroke,
+                    gap: stroke,
+                }
+            }
+            DigitStyle::Block => {
+                let block = height / 5;
+                Self {
+                    height: block * 5,
+                    digit: block * 3,
+                    stroke: block,
+                    point: block,
+                    gap: block,
+                }
+            }
+        }
+    }
+
+    /// the width of the given amount of digits and decimal points
+    fn width(&self, digits: u32, points: u32) -> u32 {
+        (digits * (self.digit + self.gap) + points * (self.point + self.gap)).saturating_sub(self.gap)
+    }
+}
+
+/// the amount of characters taking the room of a digit, all but the decimal point
+fn cells(text: &str) -> usize {
+    text.chars().filter(|char| *char != '.').count()
+}
+
+fn draw_segments<D>(display: &mut D, origin: Point, metrics: &Metrics, char: char) -> Result<(), DisplayError>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    let segments = match char {
+        '-' => MINUS_SEGMENTS,
+        _ => match char.to_digit(10) {
+            Some(digit) => SEGMENTS[digit as usize],
+            None => return Ok(()),
+        },
+    };
+
+    let (width, height, stroke) = (metrics.digit as i32, metrics.height as i32, metrics.stroke as i32);
+    let middle = height / 2;
+    let horizontal = Size::new((width - 2 * stroke).max(1) as u32, stroke as u32);
+    let upper = Size::new(stroke as u32, (middle - stroke).max(1) as u32);
+    let lower = Size::new(stroke as u32, (height - middle - stroke).max(1) as u32);
+    let areas = [
+        Rectangle::new(Point::new(stroke, 0), horizontal),
+        Rectangle::new(Point::new(width - stroke, stroke), upper),
+        Rectangle::new(Point::new(width - stroke, middle), lower),
+        Rectangle::new(Point::new(stroke, height - stroke), horizontal),
+        Rectangle::new(Point::new(0, middle), lower),
+        Rectangle::new(Point::new(0, stroke), upper),
+        Rectangle::new(Point::new(stroke, middle - stroke / 2), horizontal),
+    ];
+    for (segment, area) in areas.iter().enumerate() {
+        if segments & (1 << segment) != 0 {
+            display.fill_solid(&Rectangle::new(origin + area.top_left, area.size), BinaryColor::On)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn draw_blocks<D>(display: &mut D, origin: Point, metrics: &Metrics, char: char) -> Result<(), DisplayError>
+where
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    let rows = match char {
+        '-' => MINUS_BLOCKS,
+        _ => match char.to_digit(10) {
+            Some(digit) => BLOCKS[digit as usize],
+            None => return Ok(()),
+        },
+    };
+
+    let block = metrics.stroke;
+    for (row, bits) in rows.iter().enumerate() {
+        for column in 0..3 {
+            if bits & (0b100 >> column) != 0 {
+                let position = origin + Point::new((column * block) as i32, (row as u32 * block) as i32);
+                display.fill_solid(&Rectangle::new(position, Size::new(block, block)), BinaryColor::On)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
+use core::marker::PhantomData;
 use display_interface::DisplayError;
 use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
 use embedded_graphics::text::Text;
 use embedded_graphics_core::draw_target::DrawTarget;
 use embedded_graphics_core::Drawable;
@@ -7,10 +9,15 @@ use embedded_graphics_core::geometry::Point;
 use embedded_graphics_core::pixelcolor::BinaryColor;
 use heapless::String;
 use pagrs_core::Page;
+use crate::text_box::TextLayout;
 
 #[derive(Debug)]
 /// display a dynamically changing text on the page. maximum length and refresh rate are controlled
 /// by type parameters.
+///
+/// ## type parameters
+/// - `C`: the character style of the text, a `MonoTextStyle` unless created [`styled`](DynamicText::styled), e.g. in
+///   the `U8g2TextStyle` of `u8g2-fonts` or the `BdfTextStyle` of `eg-bdf`
 /// 
 /// ## examples
 /// 
@@ -42,30 +49,79 @@ use pagrs_core::Page;
 ///         &FONT_6X10,
 ///     );
 /// ```
+///
+/// ### wrapped and centered on the display
+/// ```rust
+/// # use std::str::FromStr;
+/// # use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_core::{HorizontalAlignment, VerticalAlignment};
+/// use pagrs_text::{DynamicText, TextLayout};
+///
+/// let mut dynamic_text = DynamicText::<_, 64, 1>::with_layout(
+///         || {
+///             heapless::String::from_str("Some potentially changing value, too long for a single line.").unwrap()
+///         },
+///         &FONT_6X10,
+///         TextLayout::new().aligned(HorizontalAlignment::Center, VerticalAlignment::Center),
+///     );
+/// ```
 /// 
-pub struct DynamicText<'a, F, const LENGTH: usize = 64, const FRAMES_PER_SECOND: u8 = 24>
+pub struct DynamicText<'a, F, const LENGTH: usize = 64, const FRAMES_PER_SECOND: u8 = 24, C = MonoTextStyle<'a, BinaryColor>>
 where F: Fn() -> String<LENGTH> {
     query_text: F,
-    font: &'a MonoFont<'a>
+    style: C,
+    layout: Option<TextLayout>,
+    /// the lifetime of the font of the default style
+    font: PhantomData<&'a ()>,
 }
 
 impl<'a, F, const LENGTH: usize, const FRAMES_PER_SECOND: u8> DynamicText<'a, F, LENGTH, FRAMES_PER_SECOND>
 where F: Fn() -> String<LENGTH> {
     pub fn new(query_text: F, font: &'a MonoFont<'a>) -> Self{
-        Self{
+        Self::styled(query_text, MonoTextStyle::new(font, BinaryColor::On))
+    }
+
+    /// create a new [DynamicText] wrapped into the display area and placed according to the `layout`, like a
+    /// [TextBox](crate::TextBox)
+    pub fn with_layout(query_text: F, font: &'a MonoFont<'a>, layout: TextLayout) -> Self {
+        Self::styled_with_layout(query_text, MonoTextStyle::new(font, BinaryColor::On), layout)
+    }
+}
+
+impl<'a, F, const LENGTH: usize, const FRAMES_PER_SECOND: u8, C> DynamicText<'a, F, LENGTH, FRAMES_PER_SECOND, C>
+where F: Fn() -> String<LENGTH>, C: TextRenderer<Color = BinaryColor> + Clone {
+    /// create a new [DynamicText] in any character `style`, e.g. of a proportional font
+    pub fn styled(query_text: F, style: C) -> Self {
+        Self {
             query_text,
-            font,
+            style,
+            layout: None,
+            font: PhantomData,
+        }
+    }
+
+    /// create a new [DynamicText] in any character `style`, placed according to the `layout`
+    pub fn styled_with_layout(query_text: F, style: C, layout: TextLayout) -> Self {
+        Self {
+            query_text,
+            style,
+            layout: Some(layout),
+            font: PhantomData,
         }
     }
 }
 
-impl<'a, F, const LENGTH: usize, const FRAMES_PER_SECOND: u8, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for DynamicText<'a, F, LENGTH, FRAMES_PER_SECOND>
-where F: Fn() -> String<LENGTH> {
+impl<'a, F, const LENGTH: usize, const FRAMES_PER_SECOND: u8, C, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for DynamicText<'a, F, LENGTH, FRAMES_PER_SECOND, C>
+where F: Fn() -> String<LENGTH>, C: TextRenderer<Color = BinaryColor> + Clone {
     fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
         let content = &self.query_text;
         let content = content();
-        let style = MonoTextStyle::new(self.font, BinaryColor::On);
-        let text = Text::new(content.as_str(), Point::new(0, self.font.character_size.height as i32), style);
+        if let Some(layout) = &self.layout {
+            return layout.draw(content.as_str(), &self.style, display);
+        }
+
+        let position = Point::new(0, self.style.line_height() as i32);
+        let text = Text::new(content.as_str(), position, self.style.clone());
         text.draw(display)?;
 
         Ok(())
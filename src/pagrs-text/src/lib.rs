@@ -2,6 +2,18 @@
 
 mod static_text;
 mod dynamic_text;
+mod text_box;
+mod marquee;
+mod paged_text;
+mod big_number;
+mod template;
+mod template_text;
 
 pub use static_text::StaticText;
-pub use dynamic_text::DynamicText;
\ No newline at end of file
+pub use dynamic_text::DynamicText;
+pub use text_box::{wrap, TextBox, TextLayout};
+pub use marquee::{Marquee, MarqueeDirection};
+pub use paged_text::PagedText;
+pub use big_number::{BigNumber, DigitStyle};
+pub use template::{TemplateError, Value, ValueSource, Values};
+pub use template_text::TemplateText;
\ No newline at end of file
@@ -0,0 +1,314 @@
+use core::marker::PhantomData;
+use display_interface::DisplayError;
+use embassy_time::{Duration, Instant};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{Point, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use pagrs_core::{Page, Refresh};
+use crate::text_box::{width, wrap_styled};
+
+/// the default speed of a [Marquee], in pixels per second
+const DEFAULT_SPEED: u8 = 24;
+/// the default time a [Marquee] rests at the start and at the end of its text
+const DEFAULT_PAUSE: Duration = Duration::from_secs(1);
+
+/// in which direction the text of a [Marquee] moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MarqueeDirection {
+    /// a single line, vertically centered, moving to the left
+    Horizontal,
+    /// the text wrapped into lines, moving up
+    Vertical,
+}
+
+/// a page scrolling a text which doesn't fit onto the display, e.g. a news ticker.
+///
+/// Each pass rests at the start of the text, moves it at the configured speed until its end is visible and rests
+/// there again, before the next pass starts from the beginning. The text is queried at the start of each pass, so
+/// it never changes while moving. A text which fits onto the display isn't moved at all.
+///
+/// The position follows the time since the pass started, so frames rendered in between (e.g. after a command of the
+/// [PageController](pagrs_core::PageController)) don't move the text any faster.
+///
+/// ## type parameters
+/// - `F`: the callback querying the text, like the one of a [DynamicText](crate::DynamicText)
+/// - `S`: the text returned by the callback, e.g. a `&'static str` or a `heapless::String`
+/// - `C`: the character style of the text, a `MonoTextStyle` unless created [`styled`](Marquee::styled)
+///
+/// ## examples
+///
+/// ### static text
+/// ```rust
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_text::Marquee;
+///
+/// let mut ticker = Marquee::new(|| "+++ the coffee machine on the 2nd floor is out of order +++", &FONT_6X10);
+/// ```
+///
+/// ### dynamic text moving up, keeping the page until it was read once
+/// ```rust
+/// # use core::fmt::Write;
+/// use embassy_time::Duration;
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_text::{Marquee, MarqueeDirection};
+///
+/// let mut news = Marquee::new(
+///         || {
+///             let mut text = heapless::String::<128>::new();
+///             write!(text, "{} new messages", 3).unwrap();
+///             text
+///         },
+///         &FONT_6X10,
+///     )
+///     .with_direction(MarqueeDirection::Vertical)
+///     .with_speed(12)
+///     .with_pauses(Duration::from_secs(2), Duration::from_millis(500))
+///     .hold_until_done();
+/// ```
+#[derive(Debug)]
+pub struct Marquee<'a, F, S, C = MonoTextStyle<'a, BinaryColor>>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+{
+    query_text: F,
+    style: C,
+    direction: MarqueeDirection,
+    speed: u8,
+    pause_at_start: Duration,
+    pause_at_end: Duration,
+    looping: bool,
+    hold_until_done: bool,
+    /// the text of the current pass
+    text: Option<S>,
+    /// when the current pass started, set by the first frame
+    pass_started: Option<Instant>,
+    /// if a full pass completed since the page got activated
+    done: bool,
+    /// the lifetime of the font of the default style
+    font: PhantomData<&'a ()>,
+}
+
+impl<'a, F, S> Marquee<'a, F, S>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+{
+    /// create a new horizontal [Marquee] in the `font`, moving 24 pixels per second and resting for a second at the
+    /// start and at the end of the text
+    pub fn new(query_text: F, font: &'a MonoFont<'a>) -> Self {
+        Self::styled(query_text, MonoTextStyle::new(font, BinaryColor::On))
+    }
+}
+
+impl<'a, F, S, C> Marquee<'a, F, S, C>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    /// create a new horizontal [Marquee] in any character `style`, e.g. of a proportional font
+    pub fn styled(query_text: F, style: C) -> Self {
+        Self {
+            query_text,
+            style,
+            direction: MarqueeDirection::Horizontal,
+            speed: DEFAULT_SPEED,
+            pause_at_start: DEFAULT_PAUSE,
+            pause_at_end: DEFAULT_PAUSE,
+            looping: true,
+            hold_until_done: false,
+            text: None,
+            pass_started: None,
+            done: false,
+            font: PhantomData,
+        }
+    }
+
+    pub fn with_direction(self, direction: MarqueeDirection) -> Self {
+        Self { direction, ..self }
+    }
+
+    /// move the text by the given amount of pixels per second, which is also the framerate of the page
+    pub fn with_speed(self, pixels_per_second: u8) -> Self {
+        Self {
+            speed: pixels_per_second.max(1),
+            ..self
+        }
+    }
+
+    /// rest for the given time at the start and at the end of the text
+    pub fn with_pauses(self, at_start: Duration, at_end: Duration) -> Self {
+        Self {
+            pause_at_start: at_start,
+            pause_at_end: at_end,
+            ..self
+        }
+    }
+
+    /// stop at the end of the text after the first pass, instead of starting over
+    pub fn once(self) -> Self {
+        Self { looping: false, ..self }
+    }
+
+    /// keep the page shown after its duration ran out, until the text passed by completely once
+    pub fn hold_until_done(self) -> Self {
+        Self {
+            hold_until_done: true,
+            ..self
+        }
+    }
+
+    /// how many pixels the text moves within the `duration`, rounded as the frames are rendered a little earlier or
+    /// later than exactly one interval apart
+    fn pixels(&self, duration: Duration) -> u32 {
+        ((duration.as_micros() * self.speed as u64 + 500_000) / 1_000_000) as u32
+    }
+}
+
+impl<'a, F, S, C, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for Marquee<'a, F, S, C>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.text = None;
+        self.pass_started = None;
+        self.done = false;
+
+        Ok(())
+    }
+
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let now = Instant::now();
+        let pause_at_start = self.pixels(self.pause_at_start);
+        let pause_at_end = self.pixels(self.pause_at_end);
+        let size = display.bounding_box().size;
+
+        // the pass is over once the text rested at its end, a looping marquee starts the next one right away
+        if let (Some(text), Some(started)) = (self.text.as_ref(), self.pass_started) {
+            let pass = pause_at_start + distance(self.direction, text.as_ref(), &self.style, size) + pause_at_end;
+            if self.pixels(now - started) >= pass.max(1) {
+                self.done = true;
+                if self.looping {
+                    self.text = None;
+                    self.pass_started = None;
+                }
+            }
+        }
+
+        let started = *self.pass_started.get_or_insert(now);
+        let moved = self.pixels(now - started);
+        let text: &S = self.text.get_or_insert_with(|| (self.query_text)());
+        let text = text.as_ref();
+
+        let offset = moved.saturating_sub(pause_at_start).min(distance(self.direction, text, &self.style, size));
+        match self.direction {
+            MarqueeDirection::Horizontal => draw_horizontal(text, &self.style, offset, display)?,
+            MarqueeDirection::Vertical => draw_vertical(text, &self.style, offset, display)?,
+        }
+
+        Ok(())
+    }
+
+    fn refresh(&self) -> Refresh {
+        Refresh::FramesPerSecond(self.speed)
+    }
+
+    fn hold(&self) -> bool {
+        self.hold_until_done && !self.done
+    }
+}
+
+/// how far the `text` has to move until its end is visible
+fn distance<C: TextRenderer>(direction: MarqueeDirection, text: &str, style: &C, size: Size) -> u32 {
+    match direction {
+        MarqueeDirection::Horizontal => horizontal_distance(text, style, size),
+        MarqueeDirection::Vertical => vertical_distance(text, style, size),
+    }
+}
+
+/// how many pixels the single line of `text` is wider than the display
+fn horizontal_distance<C: TextRenderer>(text: &str, style: &C, size: Size) -> u32 {
+    width(style, text).saturating_sub(size.width)
+}
+
+/// how many pixels the wrapped `text` is higher than the display
+fn vertical_distance<C: TextRenderer>(text: &str, style: &C, size: Size) -> u32 {
+    let lines = wrap_styled(text, size.width, style).count() as u32;
+    (lines * style.line_height()).saturating_sub(size.height)
+}
+
+/// draw the characters of the line visible when moved to the left by `offset` pixels
+fn draw_horizontal<C, D>(text: &str, style: &C, offset: u32, display: &mut D) -> Result<(), DisplayError>
+where
+    C: TextRenderer<Color = BinaryColor>,
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    let size = display.bounding_box().size;
+    // the character partially visible at the left edge, up to the one beyond the right edge
+    let first = first_char_after(text, style, offset).saturating_sub(1);
+    let last = first_char_after(text, style, offset + size.width);
+    let start = byte_index(text, first);
+    let end = byte_index(text, last);
+
+    let position = Point::new(
+        char_start(text, style, start) as i32 - offset as i32,
+        (size.height.saturating_sub(style.line_height()) / 2) as i32,
+    );
+    style.draw_string(&text[start..end], position, Baseline::Top, display)?;
+
+    Ok(())
+}
+
+/// draw the wrapped lines visible when moved up by `offset` pixels
+fn draw_vertical<C, D>(text: &str, style: &C, offset: u32, display: &mut D) -> Result<(), DisplayError>
+where
+    C: TextRenderer<Color = BinaryColor>,
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    let size = display.bounding_box().size;
+    let height = style.line_height().max(1);
+    let skipped = (offset / height) as usize;
+    // one more line is partially visible at each edge
+    let visible = (size.height / height) as usize + 2;
+
+    let mut y = -((offset % height) as i32);
+    for line in wrap_styled(text, size.width, style).skip(skipped).take(visible) {
+        style.draw_string(line, Point::new(0, y), Baseline::Top, display)?;
+        y += height as i32;
+    }
+
+    Ok(())
+}
+
+/// the number of the first character of the `text` starting further right than `x`, searched in halves as each
+/// measurement takes time
+fn first_char_after<C: TextRenderer>(text: &str, style: &C, x: u32) -> usize {
+    let (mut low, mut high) = (0, text.chars().count());
+    while low < high {
+        let middle = (low + high) / 2;
+        if char_start(text, style, byte_index(text, middle)) > x {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    low
+}
+
+/// where the character at the byte `index` of the `text` starts, relative to the start of the text
+fn char_start<C: TextRenderer>(text: &str, style: &C, index: usize) -> u32 {
+    let length = text[index..].chars().next().map_or(0, char::len_utf8);
+    width(style, &text[..index + length]) - width(style, &text[index..index + length])
+}
+
+fn byte_index(text: &str, char: usize) -> usize {
+    text.char_indices().nth(char).map_or(text.len(), |(index, _)| index)
+}
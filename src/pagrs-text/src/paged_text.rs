@@ -0,0 +1,203 @@
+use core::fmt::Write;
+use core::marker::PhantomData;
+use display_interface::DisplayError;
+use embassy_time::{Duration, Instant};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::Point;
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use heapless::String;
+use pagrs_core::{InputEvent, Page, Refresh};
+use crate::text_box::{width, wrap_styled};
+
+/// the default time each screen of a [PagedText] is shown
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// a page splitting a text too long for the display into several screens, flipping through them while it is shown.
+///
+/// The text is wrapped into lines, as many as fit onto the display form a screen. If there is more than one screen,
+/// the last line of the display shows which screen is visible, e.g. `2/3`. The page asks the
+/// [PageRotator](pagrs_core::PageRotator) to be shown long enough for all of its screens.
+///
+/// The screens can also be flipped via input: down (or a step of a rotary encoder clockwise) shows the next screen,
+/// up the previous one. Beyond the first and the last screen the input navigates to the other pages as usual.
+///
+/// The text is queried when the page gets activated, so the screens don't change while flipping through them.
+///
+/// ## type parameters
+/// - `F`: the callback querying the text, like the one of a [DynamicText](crate::DynamicText)
+/// - `S`: the text returned by the callback, e.g. a `&'static str` or a `heapless::String`
+/// - `C`: the character style of the text, a `MonoTextStyle` unless created [`styled`](PagedText::styled)
+///
+/// ## example
+/// ```rust
+/// use embassy_time::Duration;
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_text::PagedText;
+///
+/// let mut log = PagedText::new(
+///         || "sensor 3 lost connection at 12:04, reconnected at 12:07. battery of sensor 5 below 10%.",
+///         &FONT_6X10,
+///     )
+///     .with_interval(Duration::from_secs(4));
+/// ```
+#[derive(Debug)]
+pub struct PagedText<'a, F, S, C = MonoTextStyle<'a, BinaryColor>>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+{
+    query_text: F,
+    style: C,
+    interval: Duration,
+    /// the text shown since the page got activated
+    text: Option<S>,
+    /// the amount of screens of the text, known after it was rendered once
+    screens: Option<usize>,
+    /// the screen shown at `shown_since`
+    first_screen: usize,
+    shown_since: Option<Instant>,
+    /// the lifetime of the font of the default style
+    font: PhantomData<&'a ()>,
+}
+
+impl<'a, F, S> PagedText<'a, F, S>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+{
+    /// create a new [PagedText] in the `font`, showing each screen for 3 seconds
+    pub fn new(query_text: F, font: &'a MonoFont<'a>) -> Self {
+        Self::styled(query_text, MonoTextStyle::new(font, BinaryColor::On))
+    }
+}
+
+impl<'a, F, S, C> PagedText<'a, F, S, C>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    /// create a new [PagedText] in any character `style`, e.g. of a proportional font
+    pub fn styled(query_text: F, style: C) -> Self {
+        Self {
+            query_text,
+            style,
+            interval: DEFAULT_INTERVAL,
+            text: None,
+            screens: None,
+            first_screen: 0,
+            shown_since: None,
+            font: PhantomData,
+        }
+    }
+
+    /// show each screen for the `interval`
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// the screen shown at the given time
+    fn screen(&self, now: Instant, screens: usize) -> usize {
+        let elapsed = self.shown_since.map_or(Duration::from_ticks(0), |since| now - since);
+        // rounded, as the frames are rendered a little earlier or later than exactly one interval apart
+        let interval = self.interval.as_ticks().max(1);
+        let flipped = (elapsed.as_ticks() + interval / 2) / interval;
+        (self.first_screen + flipped as usize) % screens
+    }
+
+    /// show the screen `steps` ahead (or back if negative), if there is one
+    fn flip(&mut self, steps: i32) -> bool {
+        let Some(screens) = self.screens else {
+            return false;
+        };
+
+        let now = Instant::now();
+        let target = self.screen(now, screens) as i32 + steps;
+        if target < 0 || target >= screens as i32 {
+            return false;
+        }
+
+        self.first_screen = target as usize;
+        self.shown_since = Some(now);
+        true
+    }
+}
+
+impl<'a, F, S, C, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for PagedText<'a, F, S, C>
+where
+    F: Fn() -> S,
+    S: AsRef<str>,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    fn activated(&mut self) -> Result<(), DisplayError> {
+        self.text = Some((self.query_text)());
+        self.screens = None;
+        self.first_screen = 0;
+        self.shown_since = None;
+
+        Ok(())
+    }
+
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let now = Instant::now();
+        self.shown_since.get_or_insert(now);
+        // nothing to show before the page got activated
+        let Some(text) = self.text.as_ref().map(AsRef::as_ref) else {
+            return Ok(());
+        };
+
+        let size = display.bounding_box().size;
+        let height = self.style.line_height().max(1);
+
+        // with more than one screen the last line is taken by the indicator
+        let lines = wrap_styled(text, size.width, &self.style).count();
+        let rows = (size.height / height).max(1) as usize;
+        let (rows, screens) = if lines <= rows {
+            (rows, 1)
+        } else {
+            let rows = (rows - 1).max(1);
+            (rows, lines.div_ceil(rows))
+        };
+
+        let screen = self.screen(now, screens);
+        let mut y = 0;
+        for line in wrap_styled(text, size.width, &self.style).skip(screen * rows).take(rows) {
+            self.style.draw_string(line, Point::new(0, y), Baseline::Top, display)?;
+            y += height as i32;
+        }
+
+        if screens > 1 {
+            let mut indicator = String::<16>::new();
+            // only cut off for millions of screens, far more than any text held in memory
+            let _ = write!(indicator, "{}/{}", screen + 1, screens);
+            let position = Point::new(
+                size.width.saturating_sub(width(&self.style, &indicator)) as i32,
+                size.height.saturating_sub(height) as i32,
+            );
+            self.style.draw_string(&indicator, position, Baseline::Top, display)?;
+        }
+
+        self.screens = Some(screens);
+        Ok(())
+    }
+
+    fn refresh(&self) -> Refresh {
+        Refresh::Every(self.interval)
+    }
+
+    fn input(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::Down => self.flip(1),
+            InputEvent::Up => self.flip(-1),
+            InputEvent::Rotate(steps) => self.flip(steps as i32),
+            _ => false,
+        }
+    }
+
+    fn required_duration(&self) -> Option<Duration> {
+        self.screens.map(|screens| self.interval * screens as u32)
+    }
+}
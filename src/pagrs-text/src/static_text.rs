@@ -1,11 +1,12 @@
 use display_interface::DisplayError;
 use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
 use embedded_graphics::text::Text;
 use embedded_graphics_core::draw_target::DrawTarget;
 use embedded_graphics_core::Drawable;
 use embedded_graphics_core::geometry::Point;
 use embedded_graphics_core::pixelcolor::BinaryColor;
-use pagrs_core::Page;
+use pagrs_core::{Page, Refresh};
 
 #[derive(Debug)]
 ///
@@ -15,8 +16,12 @@ use pagrs_core::Page;
 /// 
 /// let mut text = StaticText::new("Hello, World!", &FONT_6X10);
 /// ```
-pub struct StaticText<'a> {
-    text: Text<'a, MonoTextStyle<'a, BinaryColor>>,
+///
+/// ## type parameters
+/// - `C`: the character style of the text, e.g. a `MonoTextStyle`, the `U8g2TextStyle` of `u8g2-fonts` or the
+///   `BdfTextStyle` of `eg-bdf`, see [`styled`](StaticText::styled)
+pub struct StaticText<'a, C = MonoTextStyle<'a, BinaryColor>> {
+    text: Text<'a, C>,
 }
 
 impl<'a> StaticText<'a> {
@@ -29,14 +34,25 @@ impl<'a> StaticText<'a> {
     }
 }
 
-impl<'a, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for StaticText<'a> {
+impl<'a, C: TextRenderer<Color = BinaryColor>> StaticText<'a, C> {
+    /// create a new [StaticText] in any character `style`, e.g. of a proportional font
+    pub fn styled(text: &'a str, style: C) -> Self {
+        let height = style.line_height();
+
+        Self {
+            text: Text::new(text, Point::new(0, height as i32), style)
+        }
+    }
+}
+
+impl<'a, C: TextRenderer<Color = BinaryColor>, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for StaticText<'a, C> {
     fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
         self.text.draw(display)?;
 
         Ok(())
     }
 
-    fn frames_per_second(&self) -> u8 {
-        1
+    fn refresh(&self) -> Refresh {
+        Refresh::OnDemand
     }
 }
@@ -0,0 +1,358 @@
+use core::cell::Cell;
+use core::fmt::Write;
+use core::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU8, AtomicUsize, Ordering,
+};
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+use heapless::{String, Vec};
+use pagrs_core::HorizontalAlignment;
+
+/// the most texts and placeholders a template consists of
+const MAX_PARTS: usize = 16;
+/// the most characters of a single formatted value, before padding
+const MAX_VALUE_LENGTH: usize = 48;
+
+/// errors of parsing the template of a [TemplateText](crate::TemplateText)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TemplateError {
+    /// a `{` without its closing `}`
+    UnclosedPlaceholder,
+    /// a `}` without an opening `{`, the brace itself is written as `}}`
+    UnmatchedBrace,
+    /// the index, width or precision of a placeholder is not a number, or the format is not known
+    InvalidPlaceholder,
+    /// a placeholder refers to the value of the given index, but there are less values
+    MissingValue(u8),
+    /// the template consists of more than 16 placeholders and texts between them
+    TooManyParts,
+}
+
+/// a single value inserted into a template
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Value {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f32),
+    Bool(bool),
+    Text(&'static str),
+}
+
+macro_rules! value_from {
+    ($variant:ident as $target:ty: $($source:ty),+) => {
+        $(
+            impl From<$source> for Value {
+                fn from(value: $source) -> Self {
+                    Value::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+value_from!(Signed as i64: i8, i16, i32, i64, isize);
+value_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+value_from!(Float as f32: f32);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<&'static str> for Value {
+    fn from(value: &'static str) -> Self {
+        Value::Text(value)
+    }
+}
+
+/// where the value of a placeholder is read from, each time the template is formatted.
+///
+/// Implemented for closures returning anything convertible into a [Value], and for references to atomics, to
+/// [Cell]s and to the `Cell`s in a blocking `Mutex` of `embassy-sync`, which the application keeps updated.
+pub trait ValueSource {
+    fn value(&self) -> Value;
+}
+
+impl<F, T> ValueSource for F
+where
+    F: Fn() -> T,
+    T: Into<Value>,
+{
+    fn value(&self) -> Value {
+        self().into()
+    }
+}
+
+macro_rules! atomic_source {
+    ($($atomic:ty),+) => {
+        $(
+            impl ValueSource for &$atomic {
+                fn value(&self) -> Value {
+                    self.load(Ordering::Relaxed).into()
+                }
+            }
+        )+
+    };
+}
+
+atomic_source!(AtomicBool, AtomicI8, AtomicU8, AtomicI16, AtomicU16, AtomicI32, AtomicU32, AtomicIsize, AtomicUsize);
+
+impl<T: Copy + Into<Value>> ValueSource for &Cell<T> {
+    fn value(&self) -> Value {
+        self.get().into()
+    }
+}
+
+impl<M: RawMutex, T: Copy + Into<Value>> ValueSource for &Mutex<M, Cell<T>> {
+    fn value(&self) -> Value {
+        self.lock(|cell| cell.get()).into()
+    }
+}
+
+/// the values bound to the placeholders of a template, by their index.
+///
+/// Implemented for tuples of up to 8 [ValueSource]s of any type, and for arrays of sources of the same type.
+pub trait Values {
+    /// the amount of values
+    const COUNT: usize;
+
+    fn value(&self, index: usize) -> Option<Value>;
+}
+
+macro_rules! tuple_values {
+    ($count:literal: $($index:tt $source:ident),+) => {
+        impl<$($source: ValueSource),+> Values for ($($source,)+) {
+            const COUNT: usize = $count;
+
+            fn value(&self, index: usize) -> Option<Value> {
+                match index {
+                    $($index => Some(self.$index.value()),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+tuple_values!(1: 0 A);
+tuple_values!(2: 0 A, 1 B);
+tuple_values!(3: 0 A, 1 B, 2 C);
+tuple_values!(4: 0 A, 1 B, 2 C, 3 D);
+tuple_values!(5: 0 A, 1 B, 2 C, 3 D, 4 E);
+tuple_values!(6: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+tuple_values!(7: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+tuple_values!(8: 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+impl<S: ValueSource, const N: usize> Values for [S; N] {
+    const COUNT: usize = N;
+
+    fn value(&self, index: usize) -> Option<Value> {
+        self.get(index).map(ValueSource::value)
+    }
+}
+
+/// a template parsed into the texts and the placeholders between them
+#[derive(Debug)]
+pub(crate) struct Template<'a> {
+    parts: Vec<Part<'a>, MAX_PARTS>,
+}
+
+#[derive(Debug)]
+enum Part<'a> {
+    Text(&'a str),
+    Placeholder(Placeholder),
+}
+
+/// a placeholder like `{0:>5.1}`, the same syntax as of `core::fmt` except for the sign flags
+#[derive(Debug)]
+struct Placeholder {
+    index: u8,
+    fill: char,
+    /// the alignment within the width, numbers are aligned right and everything else left by default
+    alignment: Option<HorizontalAlignment>,
+    /// pad numbers with zeros after their sign
+    zeros: bool,
+    width: u8,
+    precision: Option<u8>,
+}
+
+impl<'a> Template<'a> {
+    pub(crate) fn parse(template: &'a str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut add = |part| parts.push(part).map_err(|_| TemplateError::TooManyParts);
+        // the index of the next placeholder without one, like `{}`
+        let mut next_index = 0;
+        let mut rest = template;
+        while let Some(brace) = rest.find(['{', '}']) {
+            let (text, tail) = rest.split_at(brace);
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                // the text including the first brace
+                add(Part::Text(&rest[..brace + 1]))?;
+                rest = &tail[2..];
+                continue;
+            }
+
+            if !text.is_empty() {
+                add(Part::Text(text))?;
+            }
+            if tail.starts_with('}') {
+                return Err(TemplateError::UnmatchedBrace);
+            }
+            let end = tail.find('}').ok_or(TemplateError::UnclosedPlaceholder)?;
+            add(Part::Placeholder(Placeholder::parse(&tail[1..end], &mut next_index)?))?;
+            rest = &tail[end + 1..];
+        }
+
+        if !rest.is_empty() {
+            add(Part::Text(rest))?;
+        }
+        Ok(Self { parts })
+    }
+
+    /// the indices of the values the placeholders refer to
+    pub(crate) fn indices(&self) -> impl Iterator<Item = u8> + '_ {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Placeholder(placeholder) => Some(placeholder.index),
+            Part::Text(_) => None,
+        })
+    }
+
+    /// the template with the current `values` inserted, cut off if longer than the `output`
+    pub(crate) fn format<V: Values, const LENGTH: usize>(&self, values: &V, output: &mut String<LENGTH>) {
+        output.clear();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => push(output, text),
+                Part::Placeholder(placeholder) => {
+                    if let Some(value) = values.value(placeholder.index as usize) {
+                        placeholder.format(value, output);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Placeholder {
+    /// parse the inside of the braces
+    fn parse(placeholder: &str, next_index: &mut u8) -> Result<Self, TemplateError> {
+        let (index, format) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        let index = if index.is_empty() {
+            *next_index += 1;
+            *next_index - 1
+        } else {
+            number(index)?
+        };
+
+        let mut chars = format.chars();
+        let (fill, alignment, rest) = match (chars.next(), chars.next()) {
+            (Some(fill), Some(alignment)) if self::alignment(alignment).is_some() => {
+                (fill, self::alignment(alignment), chars.as_str())
+            }
+            (Some(alignment), _) if self::alignment(alignment).is_some() => (' ', self::alignment(alignment), &format[1..]),
+            _ => (' ', None, format),
+        };
+        let (zeros, rest) = match rest.strip_prefix('0') {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(number(precision)?)),
+            None => (rest, None),
+        };
+        let width = if width.is_empty() { 0 } else { number(width)? };
+
+        Ok(Self {
+            index,
+            fill,
+            alignment,
+            zeros,
+            width,
+            precision,
+        })
+    }
+
+    fn format<const LENGTH: usize>(&self, value: Value, output: &mut String<LENGTH>) {
+        let mut formatted = String::<MAX_VALUE_LENGTH>::new();
+        // numbers too long for the buffer are left out rather than cut off, texts are cut off at the end of the buffer
+        let written = match value {
+            Value::Signed(value) => write!(formatted, "{}", value),
+            Value::Unsigned(value) => write!(formatted, "{}", value),
+            Value::Float(value) => match self.precision {
+                Some(precision) => write!(formatted, "{:.*}", precision as usize, value),
+                None => write!(formatted, "{}", value),
+            },
+            Value::Bool(value) => write!(formatted, "{}", value),
+            Value::Text(text) => {
+                // the precision is the maximum length of a text
+                let end = self
+                    .precision
+                    .and_then(|precision| text.char_indices().nth(precision as usize))
+                    .map_or(text.len(), |(index, _)| index);
+                push(&mut formatted, &text[..end]);
+                Ok(())
+            }
+        };
+        if written.is_err() {
+            formatted.clear();
+        }
+
+        let numeric = matches!(value, Value::Signed(_) | Value::Unsigned(_) | Value::Float(_));
+        let padding = (self.width as usize).saturating_sub(formatted.chars().count());
+        if self.zeros && numeric {
+            let digits = formatted.trim_start_matches('-');
+            push(output, &formatted[..formatted.len() - digits.len()]);
+            fill(output, '0', padding);
+            push(output, digits);
+            return;
+        }
+
+        let default = if numeric { HorizontalAlignment::Right } else { HorizontalAlignment::Left };
+        let (before, after) = match self.alignment.unwrap_or(default) {
+            HorizontalAlignment::Left => (0, padding),
+            HorizontalAlignment::Center => (padding / 2, padding - padding / 2),
+            HorizontalAlignment::Right => (padding, 0),
+        };
+        fill(output, self.fill, before);
+        push(output, &formatted);
+        fill(output, self.fill, after);
+    }
+}
+
+fn alignment(char: char) -> Option<HorizontalAlignment> {
+    match char {
+        '<' => Some(HorizontalAlignment::Left),
+        '^' => Some(HorizontalAlignment::Center),
+        '>' => Some(HorizontalAlignment::Right),
+        _ => None,
+    }
+}
+
+/// a number of the placeholder, only made of digits
+fn number(text: &str) -> Result<u8, TemplateError> {
+    if text.is_empty() || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(TemplateError::InvalidPlaceholder);
+    }
+
+    text.parse().map_err(|_| TemplateError::InvalidPlaceholder)
+}
+
+/// append as much of the `text` as fits into the `output`
+fn push<const LENGTH: usize>(output: &mut String<LENGTH>, text: &str) {
+    for char in text.chars() {
+        if output.push(char).is_err() {
+            return;
+        }
+    }
+}
+
+fn fill<const LENGTH: usize>(output: &mut String<LENGTH>, fill: char, count: usize) {
+    for _ in 0..count {
+        if output.push(fill).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use core::marker::PhantomData;
+use display_interface::DisplayError;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use heapless::String;
+use pagrs_core::{Page, Refresh};
+use crate::template::{Template, TemplateError, Values};
+use crate::text_box::TextLayout;
+
+/// a page showing a text with values inserted into it, e.g. the readings of some sensors.
+///
+/// The template is parsed once when the page is created. Its placeholders use the syntax of `core::fmt`: `{0}` is
+/// replaced by the first value, `{}` by the next one, and a format after a colon sets the fill, the alignment, the
+/// width and the precision, e.g. `{0:.1}`, `{1:>4}`, `{2:*^8}` or `{3:05}`. The braces themselves are written as `{{`
+/// and `}}`.
+///
+/// The values are read on each frame from their [ValueSource](crate::ValueSource)s: closures, atomics, `Cell`s or
+/// the `Cell`s in a blocking `Mutex`. The text is formatted without allocating, and cut off if longer than `LENGTH`.
+///
+/// ## type parameters
+/// - `V`: the values, a tuple of up to 8 [ValueSource](crate::ValueSource)s or an array of them
+/// - `LENGTH`: the maximum length of the formatted text
+/// - `C`: the character style of the text, a `MonoTextStyle` unless created [`styled`](TemplateText::styled)
+///
+/// ## example
+/// ```rust
+/// use core::cell::Cell;
+/// use core::sync::atomic::{AtomicI32, Ordering};
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_text::TemplateText;
+///
+/// static TEMPERATURE: AtomicI32 = AtomicI32::new(215);
+/// let humidity = Cell::new(48u8);
+///
+/// let mut climate = TemplateText::<_, 32>::new(
+///         "Temp: {0:.1} C\nHum: {1}%",
+///         (|| TEMPERATURE.load(Ordering::Relaxed) as f32 / 10.0, &humidity),
+///         &FONT_6X10,
+///     )
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct TemplateText<'a, V, const LENGTH: usize = 64, C = MonoTextStyle<'a, BinaryColor>>
+where
+    V: Values,
+{
+    template: Template<'a>,
+    values: V,
+    style: C,
+    layout: TextLayout,
+    refresh: Refresh,
+    /// the lifetime of the font of the default style
+    font: PhantomData<&'a ()>,
+}
+
+impl<'a, V, const LENGTH: usize> TemplateText<'a, V, LENGTH>
+where
+    V: Values,
+{
+    /// create a new [TemplateText] in the `font`, aligned to the top left and refreshed once per second.
+    ///
+    /// Fails if the `template` is invalid or refers to more values than given.
+    pub fn new(template: &'a str, values: V, font: &'a MonoFont<'a>) -> Result<Self, TemplateError> {
+        Self::styled(template, values, MonoTextStyle::new(font, BinaryColor::On))
+    }
+}
+
+impl<'a, V, const LENGTH: usize, C> TemplateText<'a, V, LENGTH, C>
+where
+    V: Values,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    /// create a new [TemplateText] in any character `style`, e.g. of a proportional font
+    pub fn styled(template: &'a str, values: V, style: C) -> Result<Self, TemplateError> {
+        let template = Template::parse(template)?;
+        if let Some(index) = template.indices().find(|index| *index as usize >= V::COUNT) {
+            return Err(TemplateError::MissingValue(index));
+        }
+
+        Ok(Self {
+            template,
+            values,
+            style,
+            layout: TextLayout::new(),
+            refresh: Refresh::FramesPerSecond(1),
+            font: PhantomData,
+        })
+    }
+
+    /// wrap the text into the display area and place it according to the `layout`
+    pub fn with_layout(self, layout: TextLayout) -> Self {
+        Self { layout, ..self }
+    }
+
+    /// how often the values are read and shown again, once per second by default
+    pub fn with_refresh(self, refresh: Refresh) -> Self {
+        Self { refresh, ..self }
+    }
+}
+
+impl<'a, V, const LENGTH: usize, C, D: DrawTarget<Color = BinaryColor, Error = DisplayError>> Page<D> for TemplateText<'a, V, LENGTH, C>
+where
+    V: Values,
+    C: TextRenderer<Color = BinaryColor>,
+{
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        let mut text = String::<LENGTH>::new();
+        self.template.format(&self.values, &mut text);
+        self.layout.draw(&text, &self.style, display)
+    }
+
+    fn refresh(&self) -> Refresh {
+        self.refresh
+    }
+}
@@ -0,0 +1,320 @@
+use display_interface::DisplayError;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::text::renderer::TextRenderer;
+use embedded_graphics::text::Baseline;
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::Point;
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use pagrs_core::{HorizontalAlignment, Page, Refresh, VerticalAlignment};
+
+/// shown at the end of the last line, if the text doesn't fit onto the display
+const ELLIPSIS: &str = "...";
+
+/// how a [TextBox] (or a [DynamicText](crate::DynamicText)) places its text on the display.
+///
+/// The text is wrapped at the spaces between words, words longer than a line are split. A `\n` always starts a
+/// new line.
+///
+/// ## example
+/// ```rust
+/// use pagrs_core::{HorizontalAlignment, VerticalAlignment};
+/// use pagrs_text::TextLayout;
+///
+/// let layout = TextLayout::new()
+///     .aligned(HorizontalAlignment::Center, VerticalAlignment::Center)
+///     .with_line_spacing(2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TextLayout {
+    pub horizontal: HorizontalAlignment,
+    pub vertical: VerticalAlignment,
+    /// stretch the lines to the whole width of the display, except the last line of each paragraph,
+    /// which is aligned according to `horizontal`
+    pub justified: bool,
+    /// the additional pixels between two lines
+    pub line_spacing: u32,
+    /// end the last line with `...` if the text doesn't fit onto the display, otherwise it is cut off
+    pub ellipsis: bool,
+}
+
+impl TextLayout {
+    /// the text aligned to the top left, with an ellipsis if it doesn't fit
+    pub const fn new() -> Self {
+        Self {
+            horizontal: HorizontalAlignment::Left,
+            vertical: VerticalAlignment::Top,
+            justified: false,
+            line_spacing: 0,
+            ellipsis: true,
+        }
+    }
+
+    pub const fn aligned(self, horizontal: HorizontalAlignment, vertical: VerticalAlignment) -> Self {
+        Self {
+            horizontal,
+            vertical,
+            ..self
+        }
+    }
+
+    /// stretch the lines to the whole width of the display
+    pub const fn justified(self) -> Self {
+        Self { justified: true, ..self }
+    }
+
+    pub const fn with_line_spacing(self, line_spacing: u32) -> Self {
+        Self { line_spacing, ..self }
+    }
+
+    /// cut off the text which doesn't fit onto the display, without an ellipsis
+    pub const fn without_ellipsis(self) -> Self {
+        Self { ellipsis: false, ..self }
+    }
+
+    /// draw the `text` in the `style` into the whole area of the `display`
+    pub(crate) fn draw<C, D>(&self, text: &str, style: &C, display: &mut D) -> Result<(), DisplayError>
+    where
+        C: TextRenderer<Color = BinaryColor>,
+        D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+    {
+        let area = display.bounding_box();
+        let line_height = style.line_height() + self.line_spacing;
+        let rows = match area.size.height.checked_sub(style.line_height()) {
+            Some(remaining) => (remaining / line_height + 1) as usize,
+            None => 0,
+        };
+
+        let line_count = wrap_styled(text, area.size.width, style).count();
+        let shown = line_count.min(rows);
+        let truncated = line_count > rows;
+        let height = (shown as u32 * line_height).saturating_sub(self.line_spacing);
+        let top = match self.vertical {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => area.size.height.saturating_sub(height) / 2,
+            VerticalAlignment::Bottom => area.size.height.saturating_sub(height),
+        };
+
+        let mut lines = Lines::new(text, area.size.width, |line| width(style, line)).take(shown).peekable();
+        let mut y = area.top_left.y + top as i32;
+        while let Some(line) = lines.next() {
+            let last = lines.peek().is_none();
+            if last && truncated && self.ellipsis {
+                // make room for the ellipsis, dropping the space in front of it
+                let room = area.size.width.saturating_sub(width(style, ELLIPSIS));
+                let text = split_line(line.text, room, |line| width(style, line)).0;
+                let text = if width(style, text) > room { "" } else { text.trim_end_matches(' ') };
+                let x = self.offset(area.size.width, width(style, text) + width(style, ELLIPSIS));
+                let position = Point::new(area.top_left.x + x, y);
+                let end = style.draw_string(text, position, Baseline::Top, display)?;
+                style.draw_string(ELLIPSIS, end, Baseline::Top, display)?;
+            } else if self.justified && !line.ends_paragraph && !(last && truncated) && line.text.contains(' ') {
+                self.draw_justified(line.text, style, Point::new(area.top_left.x, y), area.size.width, display)?;
+            } else {
+                let x = self.offset(area.size.width, width(style, line.text));
+                let position = Point::new(area.top_left.x + x, y);
+                style.draw_string(line.text, position, Baseline::Top, display)?;
+            }
+
+            y += line_height as i32;
+        }
+
+        Ok(())
+    }
+
+    /// the horizontal offset of a line of the given width
+    fn offset(&self, available: u32, width: u32) -> i32 {
+        let free = available.saturating_sub(width);
+        match self.horizontal {
+            HorizontalAlignment::Left => 0,
+            HorizontalAlignment::Center => (free / 2) as i32,
+            HorizontalAlignment::Right => free as i32,
+        }
+    }
+
+    /// draw the words of the line with the free space distributed evenly between them
+    fn draw_justified<C, D>(&self, line: &str, style: &C, position: Point, available: u32, display: &mut D) -> Result<(), DisplayError>
+    where
+        C: TextRenderer<Color = BinaryColor>,
+        D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+    {
+        let words = line.split(' ').filter(|word| !word.is_empty());
+        let gaps = words.clone().count() as u32 - 1;
+        let used: u32 = words.clone().map(|word| width(style, word)).sum();
+
+        let free = available.saturating_sub(used);
+        let mut x = position.x;
+        for (index, word) in words.enumerate() {
+            style.draw_string(word, Point::new(x, position.y), Baseline::Top, display)?;
+            // the pixels which can't be distributed evenly go to the first gaps
+            let gap = free / gaps + u32::from((index as u32) < free % gaps);
+            x += (width(style, word) + gap) as i32;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TextLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// a page showing a static text, wrapped into the display area and placed according to a [TextLayout].
+///
+/// ## type parameters
+/// - `C`: the character style of the text, e.g. a `MonoTextStyle`, the `U8g2TextStyle` of `u8g2-fonts` or the
+///   `BdfTextStyle` of `eg-bdf`. Proportional fonts are measured for wrapping and alignment.
+///
+/// ## example
+/// ```rust
+/// use embedded_graphics::mono_font::ascii::FONT_6X10;
+/// use pagrs_core::{HorizontalAlignment, VerticalAlignment};
+/// use pagrs_text::{TextBox, TextLayout};
+///
+/// let mut text = TextBox::with_layout(
+///     "A long announcement, wrapped into as many lines as the display has.",
+///     &FONT_6X10,
+///     TextLayout::new().aligned(HorizontalAlignment::Center, VerticalAlignment::Center),
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TextBox<'a, C = MonoTextStyle<'a, BinaryColor>> {
+    text: &'a str,
+    style: C,
+    layout: TextLayout,
+}
+
+impl<'a> TextBox<'a> {
+    /// create a new [TextBox] in the `font`, aligned to the top left
+    pub const fn new(text: &'a str, font: &'a MonoFont<'a>) -> Self {
+        Self::with_layout(text, font, TextLayout::new())
+    }
+
+    pub const fn with_layout(text: &'a str, font: &'a MonoFont<'a>, layout: TextLayout) -> Self {
+        Self::styled_with_layout(text, MonoTextStyle::new(font, BinaryColor::On), layout)
+    }
+}
+
+impl<'a, C: TextRenderer<Color = BinaryColor>> TextBox<'a, C> {
+    /// create a new [TextBox] in any character `style`, aligned to the top left
+    pub const fn styled(text: &'a str, style: C) -> Self {
+        Self::styled_with_layout(text, style, TextLayout::new())
+    }
+
+    pub const fn styled_with_layout(text: &'a str, style: C, layout: TextLayout) -> Self {
+        Self { text, style, layout }
+    }
+}
+
+impl<'a, C, D> Page<D> for TextBox<'a, C>
+where
+    C: TextRenderer<Color = BinaryColor>,
+    D: DrawTarget<Color = BinaryColor, Error = DisplayError>,
+{
+    fn render(&mut self, display: &mut D) -> Result<(), DisplayError> {
+        self.layout.draw(self.text, &self.style, display)
+    }
+
+    fn refresh(&self) -> Refresh {
+        Refresh::OnDemand
+    }
+}
+
+/// wrap the `text` into lines of at most `columns` characters, as a [TextBox] does for a monospaced font.
+///
+/// ## example
+/// ```rust
+/// use pagrs_text::wrap;
+///
+/// let lines: Vec<&str> = wrap("the quick brown fox\njumps", 10).collect();
+/// assert_eq!(lines, ["the quick", "brown fox", "jumps"]);
+///
+/// // words longer than a line are split
+/// let lines: Vec<&str> = wrap("supercalifragilistic", 8).collect();
+/// assert_eq!(lines, ["supercal", "ifragili", "stic"]);
+/// ```
+pub fn wrap(text: &str, columns: usize) -> impl Iterator<Item = &str> {
+    Lines::new(text, columns as u32, |line| line.chars().count() as u32).map(|line| line.text)
+}
+
+/// wrap the `text` into lines at most `width` pixels wide, in the character `style`
+pub(crate) fn wrap_styled<'t, C: TextRenderer>(text: &'t str, width: u32, style: &'t C) -> impl Iterator<Item = &'t str> {
+    Lines::new(text, width, |line| self::width(style, line)).map(|line| line.text)
+}
+
+/// the width of the `text` in pixels, in the character `style`
+pub(crate) fn width<C: TextRenderer>(style: &C, text: &str) -> u32 {
+    style.measure_string(text, Point::zero(), Baseline::Top).bounding_box.size.width
+}
+
+struct Line<'t> {
+    text: &'t str,
+    /// if the line is the last one before a `\n` or the end of the text
+    ends_paragraph: bool,
+}
+
+/// the lines of a text wrapped at the spaces between the words
+struct Lines<'t, M> {
+    paragraphs: core::str::Split<'t, char>,
+    rest: Option<&'t str>,
+    width: u32,
+    /// the width of a part of the text
+    measure: M,
+}
+
+impl<'t, M: Fn(&str) -> u32> Lines<'t, M> {
+    fn new(text: &'t str, width: u32, measure: M) -> Self {
+        Self {
+            paragraphs: text.split('\n'),
+            rest: None,
+            width,
+            measure,
+        }
+    }
+}
+
+impl<'t, M: Fn(&str) -> u32> Iterator for Lines<'t, M> {
+    type Item = Line<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = match self.rest.take() {
+            Some(rest) => rest,
+            None => self.paragraphs.next()?.trim_end_matches('\r'),
+        };
+
+        let (text, rest) = split_line(rest.trim_start_matches(' '), self.width, &self.measure);
+        let rest = rest.trim_start_matches(' ');
+        if !rest.is_empty() {
+            self.rest = Some(rest);
+        }
+
+        Some(Line {
+            text,
+            ends_paragraph: rest.is_empty(),
+        })
+    }
+}
+
+/// split off the first line of at most `width`, at the last space that fits or within a word if there is none.
+/// The line holds at least one character, even if that is wider.
+fn split_line(text: &str, width: u32, measure: impl Fn(&str) -> u32) -> (&str, &str) {
+    let mut space = None;
+    for (index, char) in text.char_indices() {
+        if measure(&text[..index + char.len_utf8()]) > width {
+            let split = match (char, space) {
+                (' ', _) => index,
+                (_, Some(space)) => space,
+                (_, None) if index == 0 => char.len_utf8(),
+                (_, None) => index,
+            };
+            return (text[..split].trim_end_matches(' '), &text[split..]);
+        }
+        if char == ' ' {
+            space = Some(index);
+        }
+    }
+
+    (text.trim_end_matches(' '), "")
+}
@@ -13,3 +13,12 @@ pub use pagrs_screensaver::*;
 
 #[cfg(feature = "matrix")]
 pub use pagrs_matrix::*;
+
+#[cfg(feature = "input")]
+pub use pagrs_input::*;
+
+#[cfg(feature = "menu")]
+pub use pagrs_menu::*;
+
+#[cfg(feature = "schedule")]
+pub use pagrs_schedule::*;